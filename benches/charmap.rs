@@ -0,0 +1,79 @@
+//! Lookup time of charmap entries, comparing the tree of `match` closures (`charmap = tree`), the
+//! sorted table with binary search (`charmap = table`), and a bitmap font blob.
+//!
+//! For the sizes of the `.text` and `.rodata` sections, see the `charmap_tree_size` and
+//! `charmap_table_size` benchmarks.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use embedded_graphics::pixelcolor::Gray4;
+use mplusfonts::blob::BitmapFontBlob;
+use mplusfonts::{BitmapFont, BitmapFontData, CharmapEntryData, mplus, mplus_bytes};
+
+const TEXT: &str = "mplusfonts（エムプラスフォンツ）は、森下浩司によって\
+    デザインされているゴシック体の日本語フォントである。一二三上下中丸久乗九乱了予争事二互五井";
+
+static TREE: BitmapFont<'static, Gray4, 1> = mplus!(
+    font = 2,
+    size = 16,
+    charmap = tree,
+    sources = [
+        ' '..='~',
+        'ぁ'..='ゖ',
+        'ァ'..='ヺ',
+        '\u{4E00}'..='\u{51FF}',
+        ["（）、。", "ffi"]
+    ],
+);
+
+static TABLE: BitmapFont<'static, Gray4, 1> = mplus!(
+    font = 2,
+    size = 16,
+    charmap = table,
+    sources = [
+        ' '..='~',
+        'ぁ'..='ゖ',
+        'ァ'..='ヺ',
+        '\u{4E00}'..='\u{51FF}',
+        ["（）、。", "ffi"]
+    ],
+);
+
+static BYTES: &[u8] = mplus_bytes!(
+    font = 2,
+    size = 16,
+    sources = [
+        ' '..='~',
+        'ぁ'..='ゖ',
+        'ァ'..='ヺ',
+        '\u{4E00}'..='\u{51FF}',
+        ["（）、。", "ffi"]
+    ],
+);
+
+fn lookup<'a, F>(font: &F, text: &str) -> usize
+where
+    F: BitmapFontData<'a, Gray4, 1>,
+{
+    let mut slice = text;
+    let mut count = 0;
+    while let Some((index, _)) = slice.char_indices().nth(font.get(slice).advance_chars()) {
+        slice = &slice[index..];
+        count += 1;
+    }
+
+    count
+}
+
+fn bench_charmap(c: &mut Criterion) {
+    let blob = BitmapFontBlob::<Gray4, 1>::new(BYTES).expect("expected bitmap font blob");
+    let mut group = c.benchmark_group("charmap");
+    group.bench_function("tree", |b| b.iter(|| lookup(&TREE, black_box(TEXT))));
+    group.bench_function("table", |b| b.iter(|| lookup(&TABLE, black_box(TEXT))));
+    group.bench_function("blob", |b| b.iter(|| lookup(&blob, black_box(TEXT))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_charmap);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+//! Sizes of the `.text` and `.rodata` sections with a bitmap font that uses `charmap = table`.
+//! Compare the output with that of the `charmap_tree_size` benchmark, which is the same program
+//! except for the charmap.
+
+mod size;
+
+use std::hint::black_box;
+
+use embedded_graphics::pixelcolor::Gray4;
+use mplusfonts::{BitmapFont, BitmapFontData, CharmapEntryData, mplus};
+
+static FONT: BitmapFont<'static, Gray4, 1> = mplus!(
+    font = 2,
+    size = 16,
+    charmap = table,
+    sources = [
+        ' '..='~',
+        'ぁ'..='ゖ',
+        'ァ'..='ヺ',
+        '\u{4E00}'..='\u{51FF}',
+        ["（）、。", "ffi"]
+    ],
+);
+
+fn main() {
+    let entry = FONT.get(black_box("一"));
+    black_box(entry.image(0, 0));
+
+    size::print_section_sizes("charmap = table");
+}
@@ -0,0 +1,64 @@
+use std::{env, fs};
+
+/// Prints the sizes of the `.text` and `.rodata` sections of the running executable, reading the
+/// section headers of a 64-bit little-endian ELF file. Read-only data with relocations, such as
+/// references and function pointers, is in `.data.rel.ro` for position-independent executables.
+pub fn print_section_sizes(name: &str) {
+    let path = env::current_exe().expect("expected path of current executable");
+    let bytes = fs::read(path).expect("expected current executable to be readable");
+    let Some(sizes) = section_sizes(&bytes, [".text", ".rodata", ".data.rel.ro"]) else {
+        println!("{name}: expected 64-bit little-endian ELF file");
+        return;
+    };
+
+    let [text, rodata, data_rel_ro] = sizes;
+    println!(
+        "{name}: .text {text} bytes, .rodata {rodata} bytes, .data.rel.ro {data_rel_ro} bytes"
+    );
+}
+
+fn section_sizes<const N: usize>(bytes: &[u8], prefixes: [&str; N]) -> Option<[u64; N]> {
+    if bytes.get(..6)? != b"\x7FELF\x02\x01" {
+        return None;
+    }
+
+    let offset = read_u64(bytes, 0x28)? as usize;
+    let entry_size = read_u16(bytes, 0x3A)? as usize;
+    let count = read_u16(bytes, 0x3C)? as usize;
+    let names_index = read_u16(bytes, 0x3E)? as usize;
+    let header = |index: usize| bytes.get(offset + index * entry_size..)?.get(..entry_size);
+    let names_offset = read_u64(header(names_index)?, 0x18)? as usize;
+
+    let mut sizes = [0; N];
+    for index in 0..count {
+        let header = header(index)?;
+        let name_offset = names_offset + read_u32(header, 0x00)? as usize;
+        let name = bytes.get(name_offset..)?.split(|byte| *byte == 0).next()?;
+        for (prefix, size) in prefixes.iter().zip(&mut sizes) {
+            let is_match = name.strip_prefix(prefix.as_bytes());
+            if is_match.is_some_and(|rest| rest.is_empty() || rest[0] == b'.') {
+                *size += read_u64(header, 0x20)?;
+            }
+        }
+    }
+
+    Some(sizes)
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    let bytes = bytes.get(offset..offset + 2)?;
+
+    Some(u16::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let bytes = bytes.get(offset..offset + 4)?;
+
+    Some(u32::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> Option<u64> {
+    let bytes = bytes.get(offset..offset + 8)?;
+
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
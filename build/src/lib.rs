@@ -0,0 +1,256 @@
+//! Build-script API for [`mplusfonts`](https://docs.rs/mplusfonts), which renders the same bitmap
+//! fonts as [`mplus!`](https://docs.rs/mplusfonts/latest/mplusfonts/macro.mplus.html), but does so
+//! from `build.rs`, writing them to a file in `OUT_DIR`.
+//!
+//! Font rasterization inside of a proc macro happens every time the macro is expanded, including
+//! each `cargo check` that an editor runs in the background. With this crate, the fonts are only
+//! rendered again when Cargo reruns the build script, that is, when `build.rs` itself, any string
+//! sources read from files, or any font files change.
+//!
+//! # Examples
+//!
+//! In `build.rs`:
+//!
+//! ```no_run
+//! use mplusfonts_build::{BitmapFontBuilder, Builder};
+//!
+//! fn main() {
+//!     Builder::new("fonts.rs")
+//!         .font(BitmapFontBuilder::new("CLOCK_FONT", "code(100), 500, 24, true, 1, 4, '0'..='9'"))
+//!         .font(
+//!             BitmapFontBuilder::new("LABEL_FONT", "font = 2, size = 16, positions = 2")
+//!                 .strings(["Alarm", "Snooze"])
+//!                 .strings_file("strings.txt"),
+//!         )
+//!         .write()
+//!         .expect("expected fonts to be written to `OUT_DIR`");
+//! }
+//! ```
+//!
+//! In the crate that is being built:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/fonts.rs"));
+//!
+//! let character_style = BitmapFontStyle::new(&CLOCK_FONT, Rgb565::WHITE);
+//! ```
+//!
+//! Each bitmap font becomes a `static` item of type `BitmapFont`, with the name given to
+//! [`BitmapFontBuilder::new`]. The arguments are the same as those of `mplus!`, either positional
+//! or keyword arguments, as a string.
+
+use std::path::{Path, PathBuf};
+use std::{env, error, fmt, fs, io};
+
+use mplusfonts_codegen as mplus;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+
+/// Builder for a bitmap font that is going to be written to a file.
+#[derive(Debug, Clone)]
+pub struct BitmapFontBuilder {
+    name: String,
+    args: String,
+    strings: Vec<String>,
+    strings_files: Vec<PathBuf>,
+}
+
+/// Builder for a file with bitmap fonts in `OUT_DIR`.
+#[derive(Debug, Clone)]
+pub struct Builder {
+    file_name: PathBuf,
+    fonts: Vec<BitmapFontBuilder>,
+}
+
+/// Error that occurs when generating bitmap fonts.
+#[derive(Debug)]
+pub enum Error {
+    /// The name or the arguments of a bitmap font could not be parsed.
+    Parse {
+        /// The name of the bitmap font.
+        name: String,
+        /// The error message.
+        message: String,
+    },
+    /// The arguments of a bitmap font were parsed, but cannot be used together.
+    Invalid {
+        /// The name of the bitmap font.
+        name: String,
+        /// The error message.
+        message: String,
+    },
+    /// An environment variable that Cargo sets for build scripts is not set.
+    Env(&'static str),
+    /// A file could not be read or written.
+    Io {
+        /// The path to the file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: io::Error,
+    },
+}
+
+impl BitmapFontBuilder {
+    /// Creates a new builder for a bitmap font with the specified name and `mplus!` arguments.
+    pub fn new(name: impl Into<String>, args: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            args: args.into(),
+            strings: Vec::new(),
+            strings_files: Vec::new(),
+        }
+    }
+
+    /// Adds the specified strings to the bitmap font, the same as a slice literal in `mplus!`.
+    pub fn strings<I, S>(mut self, strings: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.strings.extend(strings.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds the strings from the specified file to the bitmap font, one string per line, ignoring
+    /// empty lines. The path is relative to `CARGO_MANIFEST_DIR`.
+    pub fn strings_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.strings_files.push(path.into());
+        self
+    }
+
+    /// Renders the bitmap font, returning a `static` item along with the paths of the files that
+    /// have been read.
+    fn render(&self, manifest_dir: &Path) -> Result<(TokenStream, Vec<PathBuf>), Error> {
+        let parse_error = |message: String| Error::Parse {
+            name: self.name.clone(),
+            message,
+        };
+        let ident: syn::Ident =
+            syn::parse_str(&self.name).map_err(|e| parse_error(e.to_string()))?;
+        let mut args: mplus::Arguments =
+            syn::parse_str(&self.args).map_err(|e| parse_error(e.to_string()))?;
+
+        let mut paths: Vec<_> = args.font.paths().into_iter().cloned().collect();
+        let mut strings = self.strings.clone();
+        for path in &self.strings_files {
+            let path = manifest_dir.join(path);
+            let contents = fs::read_to_string(&path).map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+            let lines = contents.lines().filter(|line| !line.is_empty());
+            strings.extend(lines.map(String::from));
+            paths.push(path);
+        }
+
+        if !strings.is_empty() {
+            args.sources.push(mplus::CharSource::Strings(strings));
+        }
+
+        let positions = args.positions as usize;
+        let color_type = match args.bit_depth {
+            1 => quote!(::embedded_graphics::pixelcolor::BinaryColor),
+            2 => quote!(::embedded_graphics::pixelcolor::Gray2),
+            4 => quote!(::embedded_graphics::pixelcolor::Gray4),
+            8 => quote!(::embedded_graphics::pixelcolor::Gray8),
+            x => panic!("expected one of: `1`, `2`, `4`, `8`; found: `{x}`"),
+        };
+        let font = mplus::bitmap_font(&mut args).map_err(|e| Error::Invalid {
+            name: self.name.clone(),
+            message: e.to_string(),
+        })?;
+        let font = font.into_token_stream();
+        let item = quote! {
+            pub static #ident: ::mplusfonts::BitmapFont<'static, #color_type, #positions> = #font;
+        };
+
+        Ok((item, paths))
+    }
+}
+
+impl Builder {
+    /// Creates a new, empty builder for a file with the specified name in `OUT_DIR`.
+    pub fn new(file_name: impl Into<PathBuf>) -> Self {
+        Self {
+            file_name: file_name.into(),
+            fonts: Vec::new(),
+        }
+    }
+
+    /// Adds the specified bitmap font to the file.
+    pub fn font(mut self, font: BitmapFontBuilder) -> Self {
+        self.fonts.push(font);
+        self
+    }
+
+    /// Renders all bitmap fonts, returning the Rust source code for the file along with the paths
+    /// of the files that have been read, relative to the specified directory.
+    pub fn generate(&self, manifest_dir: &Path) -> Result<(String, Vec<PathBuf>), Error> {
+        let mut source = String::from("// @generated by mplusfonts-build\n");
+        let mut paths = Vec::new();
+        for font in &self.fonts {
+            let (item, font_paths) = font.render(manifest_dir)?;
+            source.push_str(&item.to_string());
+            source.push('\n');
+            paths.extend(font_paths);
+        }
+
+        Ok((source, paths))
+    }
+
+    /// Renders all bitmap fonts and writes them to the file in `OUT_DIR`, returning its path.
+    ///
+    /// This also instructs Cargo to rerun the build script only if `build.rs`, a strings file, or
+    /// a font file has changed. The file is left untouched if its contents are the same, so that
+    /// the crate that includes it does not get recompiled.
+    pub fn write(&self) -> Result<PathBuf, Error> {
+        let out_dir = env::var_os("OUT_DIR").ok_or(Error::Env("OUT_DIR"))?;
+        let manifest_dir =
+            env::var_os("CARGO_MANIFEST_DIR").ok_or(Error::Env("CARGO_MANIFEST_DIR"))?;
+        let manifest_dir = PathBuf::from(manifest_dir);
+        let (source, mut paths) = self.generate(&manifest_dir)?;
+        let build_script = manifest_dir.join("build.rs");
+        if build_script.is_file() {
+            paths.push(build_script);
+        }
+
+        for path in paths {
+            println!("cargo::rerun-if-changed={}", path.display());
+        }
+
+        let path = PathBuf::from(out_dir).join(&self.file_name);
+        let is_unchanged = fs::read_to_string(&path).is_ok_and(|contents| contents == source);
+        if !is_unchanged {
+            fs::write(&path, source).map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+        }
+
+        Ok(path)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { name, message } => write!(f, "failed to parse `{name}`: {message}"),
+            Self::Invalid { name, message } => {
+                write!(f, "invalid arguments for `{name}`: {message}")
+            }
+            Self::Env(key) => write!(f, "environment variable `{key}` not set"),
+            Self::Io { path, source } => {
+                write!(f, "failed to access `{}`: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::path::Path;
+
+use mplusfonts_build::{BitmapFontBuilder, Builder, Error};
+
+macro_rules! test_generate {
+    (
+        $(
+            $fn_ident:ident, $builder:expr, $expected_idents:expr, $expected_paths:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+                let (source, paths) = $builder.generate(manifest_dir).expect("expected source");
+                let file = syn::parse_file(&source).expect("expected Rust source file");
+                let idents: Vec<_> = file
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        syn::Item::Static(item_static) => item_static.ident.to_string(),
+                        _ => panic!("expected static item"),
+                    })
+                    .collect();
+                let paths: Vec<_> = paths
+                    .iter()
+                    .map(|path| path.strip_prefix(manifest_dir).expect("expected relative path"))
+                    .collect();
+                assert_eq!(idents, $expected_idents);
+                assert_eq!(paths, $expected_paths.map(Path::new));
+            }
+        )*
+    }
+}
+
+test_generate! {
+    generate_empty,
+        Builder::new("fonts.rs"),
+        [""; 0],
+        [""; 0],
+    generate_positional_and_keyword_arguments,
+        Builder::new("fonts.rs")
+            .font(BitmapFontBuilder::new("CLOCK_FONT", "code(100), 500, 24, true, 1, 4, '0'..='9'"))
+            .font(BitmapFontBuilder::new("LABEL_FONT", "font = 2, size = 16").strings(["OK"])),
+        ["CLOCK_FONT", "LABEL_FONT"],
+        [""; 0],
+    generate_with_strings_file,
+        Builder::new("fonts.rs").font(
+            BitmapFontBuilder::new("LABEL_FONT", "font = 1, size = 16")
+                .strings_file("tests/strings.txt"),
+        ),
+        ["LABEL_FONT"],
+        ["tests/strings.txt"],
+    generate_with_font_file,
+        Builder::new("fonts.rs").font(BitmapFontBuilder::new(
+            "FILE_FONT",
+            "font = file(\"../codegen/fonts/MPLUS1[wght].ttf\"), size = 16, sources = ['0'..='9']",
+        )),
+        ["FILE_FONT"],
+        ["../codegen/fonts/MPLUS1[wght].ttf"],
+}
+
+macro_rules! test_generate_error {
+    (
+        $(
+            $fn_ident:ident, $builder:expr, $expected:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+                let Err(error) = $builder.generate(manifest_dir) else {
+                    panic!("expected error");
+                };
+                assert_eq!(error.to_string(), $expected);
+            }
+        )*
+    }
+}
+
+test_generate_error! {
+    generate_invalid_name,
+        Builder::new("fonts.rs").font(BitmapFontBuilder::new("LABEL FONT", "font = 1, size = 16")),
+        "failed to parse `LABEL FONT`: unexpected token",
+    generate_missing_size,
+        Builder::new("fonts.rs").font(BitmapFontBuilder::new("LABEL_FONT", "font = 1")),
+        "failed to parse `LABEL_FONT`: missing argument `size`",
+    generate_missing_strings_file,
+        Builder::new("fonts.rs").font(
+            BitmapFontBuilder::new("LABEL_FONT", "font = 1, size = 16").strings_file("strings.txt"),
+        ),
+        concat!(
+            "failed to access `",
+            env!("CARGO_MANIFEST_DIR"),
+            "/strings.txt`: No such file or directory (os error 2)",
+        ),
+    generate_outline_with_subpixel,
+        Builder::new("fonts.rs").font(BitmapFontBuilder::new(
+            "LABEL_FONT",
+            "font = 1, size = 16, outline = 1, subpixel = rgb",
+        )),
+        concat!(
+            "invalid arguments for `LABEL_FONT`: ",
+            "expected grayscale glyph images for outlines, remove `subpixel`",
+        ),
+    generate_vertical_with_subpixel,
+        Builder::new("fonts.rs").font(BitmapFontBuilder::new(
+            "LABEL_FONT",
+            "font = 1, size = 16, vertical = true, subpixel = rgb",
+        )),
+        concat!(
+            "invalid arguments for `LABEL_FONT`: ",
+            "expected grayscale glyph images for vertical writing, remove `subpixel`",
+        ),
+}
+
+#[test]
+fn write_without_out_dir() {
+    let Err(Error::Env(key)) = Builder::new("fonts.rs").write() else {
+        panic!("expected error");
+    };
+    assert_eq!(key, "OUT_DIR");
+}
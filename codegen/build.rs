@@ -0,0 +1,52 @@
+//! Computes a digest of everything that affects the rendered glyphs, that is, the source code of
+//! the generator, the bundled font files, and the manifest with the dependency versions, so that
+//! cache entries written by a different generator are never read.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use sha2::{Digest, Sha256};
+
+const INPUTS: [&str; 3] = ["Cargo.toml", "fonts", "src"];
+
+fn main() -> io::Result<()> {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default());
+    let mut paths = Vec::new();
+    for input in INPUTS {
+        let path = manifest_dir.join(input);
+        println!("cargo::rerun-if-changed={}", path.display());
+        collect_files(&path, &mut paths)?;
+    }
+
+    paths.sort();
+
+    let mut hasher = Sha256::new();
+    for path in paths {
+        let relative_path = path.strip_prefix(&manifest_dir).unwrap_or(&path);
+        let contents = fs::read(&path)?;
+        hasher.update(relative_path.to_string_lossy().as_bytes());
+        hasher.update((contents.len() as u64).to_le_bytes());
+        hasher.update(contents);
+    }
+
+    let digest: String = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    println!("cargo::rustc-env=MPLUSFONTS_CODEGEN_DIGEST={digest}");
+
+    Ok(())
+}
+
+fn collect_files(path: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    if path.is_dir() {
+        for entry in fs::read_dir(path)? {
+            collect_files(&entry?.path(), paths)?;
+        }
+    } else {
+        paths.push(path.to_path_buf());
+    }
+
+    Ok(())
+}
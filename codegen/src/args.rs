@@ -0,0 +1,531 @@
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Token, parse};
+
+use super::CharSource;
+use super::bitmap::{Compression, Subpixel};
+use super::charmap::CharmapKind;
+use super::font::{Font, FontFeature, FontSize, FontWeight};
+
+const KEYWORDS: [&str; 14] = [
+    "font",
+    "weight",
+    "size",
+    "hint",
+    "positions",
+    "bit_depth",
+    "charmap",
+    "compress",
+    "subpixel",
+    "outline",
+    "oblique",
+    "vertical",
+    "features",
+    "sources",
+];
+
+pub struct Arguments {
+    pub font: Font,
+    pub weight: u16,
+    pub size: f32,
+    pub hint: bool,
+    pub positions: u8,
+    pub bit_depth: u8,
+    pub charmap: CharmapKind,
+    pub compress: Compression,
+    pub subpixel: Subpixel,
+    pub outline: u8,
+    pub oblique: f32,
+    pub vertical: bool,
+    pub features: Vec<FontFeature>,
+    pub sources: Punctuated<CharSource, Token![,]>,
+}
+
+impl Parse for Arguments {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.peek2(Token![=]) {
+            return input.call(parse_keyword_arguments);
+        }
+
+        let font = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let span = input.span();
+        let weight = input.call(parse_weight(&font))?;
+        let weight = font.check_weight(weight, span)?;
+        input.parse::<Token![,]>()?;
+        let size = input.call(parse_size)?;
+        input.parse::<Token![,]>()?;
+        let hint = input.call(parse_bool)?;
+        input.parse::<Token![,]>()?;
+        let positions = input.call(parse_u8_in_range::<1, 16>)?;
+        input.parse::<Token![,]>()?;
+        let bit_depth = input.call(parse_u8_in_set::<1, 2, 4, 8>)?;
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![,]) || lookahead.peek(parse::End) {
+            input.parse::<Option<Token![,]>>()?;
+            let sources = Punctuated::parse_terminated(input)?;
+            let arguments = Self {
+                font,
+                weight,
+                size,
+                hint,
+                positions,
+                bit_depth,
+                charmap: CharmapKind::Table,
+                compress: Compression::None,
+                subpixel: Subpixel::None,
+                outline: 0,
+                oblique: 0.0,
+                vertical: false,
+                features: Vec::new(),
+                sources,
+            };
+
+            Ok(arguments)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+fn parse_keyword_arguments(input: ParseStream) -> syn::Result<Arguments> {
+    let mut values: [Option<TokenStream>; KEYWORDS.len()] = Default::default();
+    let mut sources = Punctuated::new();
+    while input.peek(syn::Ident) && input.peek2(Token![=]) {
+        let ident: syn::Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let expr: syn::Expr = input.parse()?;
+        let name = ident.to_string();
+        let Some(index) = KEYWORDS.iter().position(|keyword| *keyword == name) else {
+            let options = KEYWORDS.join("`, `");
+            let message = format!("expected one of: `{options}`; found `{name}`");
+            return Err(syn::Error::new(ident.span(), message));
+        };
+
+        if index == KEYWORDS.len() - 1 {
+            if values[index].replace(TokenStream::new()).is_some() {
+                let message = format!("duplicate argument `{name}`");
+                return Err(syn::Error::new(ident.span(), message));
+            }
+
+            sources.extend(parse_sources(expr)?);
+        } else if values[index].replace(expr.into_token_stream()).is_some() {
+            let message = format!("duplicate argument `{name}`");
+            return Err(syn::Error::new(ident.span(), message));
+        }
+
+        if input.is_empty() {
+            break;
+        }
+
+        input.parse::<Token![,]>()?;
+    }
+
+    let trailing_sources = Punctuated::<CharSource, Token![,]>::parse_terminated(input)?;
+    sources.extend(trailing_sources);
+
+    let [
+        font,
+        weight,
+        size,
+        hint,
+        positions,
+        bit_depth,
+        charmap,
+        compress,
+        subpixel,
+        outline,
+        oblique,
+        vertical,
+        features,
+        _,
+    ] = values;
+    let Some(font) = font else {
+        let message = "missing argument `font`";
+        return Err(syn::Error::new(Span::call_site(), message));
+    };
+    let font: Font = syn::parse2(font)?;
+    let weight = match weight {
+        Some(weight) => {
+            let span = weight.span();
+            let weight = parse_weight(&font).parse2(weight)?;
+
+            font.check_weight(weight, span)?
+        }
+        None => font.default_weight(),
+    };
+    let Some(size) = size else {
+        let message = "missing argument `size`";
+        return Err(syn::Error::new(Span::call_site(), message));
+    };
+    let size = parse_size.parse2(size)?;
+    let hint = match hint {
+        Some(hint) => parse_bool.parse2(hint)?,
+        None => false,
+    };
+    let positions = match positions {
+        Some(positions) => parse_u8_in_range::<1, 16>.parse2(positions)?,
+        None => 1,
+    };
+    let bit_depth = match bit_depth {
+        Some(bit_depth) => parse_u8_in_set::<1, 2, 4, 8>.parse2(bit_depth)?,
+        None => 4,
+    };
+    let charmap = match charmap {
+        Some(charmap) => syn::parse2(charmap)?,
+        None => CharmapKind::Table,
+    };
+    let compress = match compress {
+        Some(compress) => syn::parse2(compress)?,
+        None => Compression::None,
+    };
+    let subpixel = match subpixel {
+        Some(subpixel) => syn::parse2(subpixel)?,
+        None => Subpixel::None,
+    };
+    let outline = match outline {
+        Some(outline) => parse_u8_in_range::<0, 8>.parse2(outline)?,
+        None => 0,
+    };
+    let oblique = match oblique {
+        Some(oblique) => parse_f32_in_range::<-30, 30>.parse2(oblique)?,
+        None => 0.0,
+    };
+    let vertical = match vertical {
+        Some(vertical) => parse_bool.parse2(vertical)?,
+        None => false,
+    };
+    let features = match features {
+        Some(features) => parse_features(syn::parse2(features)?)?,
+        None => Vec::new(),
+    };
+    let arguments = Arguments {
+        font,
+        weight,
+        size,
+        hint,
+        positions,
+        bit_depth,
+        charmap,
+        compress,
+        subpixel,
+        outline,
+        oblique,
+        vertical,
+        features,
+        sources,
+    };
+
+    Ok(arguments)
+}
+
+fn parse_sources(expr: syn::Expr) -> syn::Result<Vec<CharSource>> {
+    let syn::Expr::Array(expr_array) = expr else {
+        let message = "expected slice literal expression";
+        return Err(syn::Error::new_spanned(expr, message));
+    };
+
+    let is_strings = expr_array.elems.iter().all(|expr| {
+        matches!(
+            expr,
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(_),
+                ..
+            })
+        )
+    });
+    if is_strings {
+        let source = expr_array.try_into()?;
+
+        return Ok(Vec::from([source]));
+    }
+
+    let exprs = expr_array.elems.into_iter();
+    let sources = exprs.map(|expr| syn::parse2(expr.into_token_stream()));
+
+    sources.collect()
+}
+
+fn parse_features(expr: syn::Expr) -> syn::Result<Vec<FontFeature>> {
+    let syn::Expr::Array(expr_array) = expr else {
+        let message = "expected slice literal expression";
+        return Err(syn::Error::new_spanned(expr, message));
+    };
+
+    let mut features: Vec<FontFeature> = Vec::new();
+    for expr in expr_array.elems {
+        let syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) = expr
+        else {
+            let message = "expected string literal";
+            return Err(syn::Error::new_spanned(expr, message));
+        };
+
+        let span = lit_str.span();
+        let feature = FontFeature::try_from(lit_str)?;
+        if features.iter().any(|other| other.tag == feature.tag) {
+            let tag = String::from_utf8_lossy(&feature.tag);
+            let message = format!("duplicate feature `{tag}`");
+            return Err(syn::Error::new(span, message));
+        }
+
+        features.push(feature);
+    }
+
+    Ok(features)
+}
+
+fn parse_weight(font: &Font) -> fn(ParseStream) -> syn::Result<u16> {
+    use Font::*;
+
+    match font {
+        MPLUS1(..) | MPLUS2(..) => |input| {
+            let FontWeight::<900>(value) = input.parse()?;
+
+            Ok(value)
+        },
+        MPLUSCode { .. } => |input| {
+            let FontWeight::<700>(value) = input.parse()?;
+
+            Ok(value)
+        },
+        File { .. } => |input| {
+            let FontWeight::<1000>(value) = input.parse()?;
+
+            Ok(value)
+        },
+        Fallback(..) => parse_weight(font.primary()),
+    }
+}
+
+fn parse_size(input: ParseStream) -> syn::Result<f32> {
+    let FontSize(value) = input.parse()?;
+
+    Ok(value)
+}
+
+fn parse_bool(input: ParseStream) -> syn::Result<bool> {
+    let syn::LitBool { value, .. } = input.parse()?;
+
+    Ok(value)
+}
+
+fn parse_u8_in_range<const MIN: u8, const MAX: u8>(input: ParseStream) -> syn::Result<u8> {
+    let lit_int: syn::LitInt = input.parse()?;
+    let value = lit_int.base10_parse()?;
+    if value < MIN || value > MAX {
+        let message = format!("expected number between `{MIN}` and `{MAX}`, found `{value}`");
+        return Err(syn::Error::new(lit_int.span(), message));
+    }
+
+    Ok(value)
+}
+
+fn parse_f32_in_range<const MIN: i8, const MAX: i8>(input: ParseStream) -> syn::Result<f32> {
+    let (value, span) = match input.parse()? {
+        syn::Lit::Int(lit_int) => (lit_int.base10_parse()?, lit_int.span()),
+        syn::Lit::Float(lit_float) => (lit_float.base10_parse()?, lit_float.span()),
+        lit => {
+            let message = "expected integer or floating point literal";
+            return Err(syn::Error::new_spanned(lit, message));
+        }
+    };
+    if value < f32::from(MIN) || value > f32::from(MAX) {
+        let message = format!("expected number between `{MIN}` and `{MAX}`, found `{value}`");
+        return Err(syn::Error::new(span, message));
+    }
+
+    Ok(value)
+}
+
+fn parse_u8_in_set<const A: u8, const B: u8, const C: u8, const D: u8>(
+    input: ParseStream,
+) -> syn::Result<u8> {
+    let lit_int: syn::LitInt = input.parse()?;
+    let value = lit_int.base10_parse()?;
+    if ![A, B, C, D].contains(&value) {
+        let message = format!("expected one of: `{A}`, `{B}`, `{C}`, `{D}`; found `{value}`");
+        return Err(syn::Error::new(lit_int.span(), message));
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_parse_arguments {
+        (
+            $(
+                $fn_ident:ident, $input:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let args: Arguments = syn::parse_str($input).expect("expected arguments");
+                    let result = (
+                        args.weight,
+                        args.size,
+                        args.hint,
+                        args.positions,
+                        args.bit_depth,
+                        args.sources.len(),
+                    );
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_parse_arguments! {
+        parse_positional_arguments,
+            "2, 575, 20, true, 4, 4, 'a'..='z'", (575, 20.0, true, 4, 4, 1),
+        parse_positional_arguments_without_sources,
+            "1, BOLD, 20, false, 2, 8", (700, 20.0, false, 2, 8, 0),
+        parse_keyword_arguments,
+            "font = 2, weight = 575, size = 20, hint = true, positions = 4, bit_depth = 4",
+            (575, 20.0, true, 4, 4, 0),
+        parse_keyword_arguments_in_any_order,
+            "bit_depth = 8, size = 20, positions = 2, font = code(100), weight = MEDIUM",
+            (500, 20.0, false, 2, 8, 0),
+        parse_keyword_arguments_with_defaults,
+            "font = 1, size = 20", (400, 20.0, false, 1, 4, 0),
+        parse_keyword_arguments_with_strings,
+            "font = 1, size = 20, sources = [\"Yes\", \"No\"]", (400, 20.0, false, 1, 4, 1),
+        parse_keyword_arguments_with_sources,
+            "font = 1, size = 20, sources = ['0'..='9', kern('a'..='z', []), [\"x\"]]",
+            (400, 20.0, false, 1, 4, 3),
+        parse_keyword_arguments_with_file,
+            "font = file(\"fonts/MPLUS2[wght].ttf\"), size = 20", (400, 20.0, false, 1, 4, 0),
+        parse_positional_arguments_with_file,
+            "file(\"fonts/MPLUSCodeLatin[wdth,wght].ttf\", 125), 700, 20, false, 1, 8",
+            (700, 20.0, false, 1, 8, 0),
+        parse_positional_arguments_with_fallback,
+            "fallback(code(100), 2, 1), 700, 20, false, 1, 4", (700, 20.0, false, 1, 4, 0),
+        parse_keyword_arguments_with_trailing_sources,
+            "font = 1, size = 20, sources = ['0'..='9'], [\"x\"], 'A'..='F'",
+            (400, 20.0, false, 1, 4, 3),
+        parse_keyword_arguments_with_charmap,
+            "font = 1, size = 20, charmap = tree, sources = ['0'..='9']", (400, 20.0, false, 1, 4, 1),
+        parse_keyword_arguments_with_compress,
+            "font = 1, size = 20, compress = rle", (400, 20.0, false, 1, 4, 0),
+        parse_keyword_arguments_with_subpixel,
+            "font = 1, size = 20, subpixel = bgr", (400, 20.0, false, 1, 4, 0),
+        parse_keyword_arguments_with_outline,
+            "font = 1, size = 20, outline = 2", (400, 20.0, false, 1, 4, 0),
+        parse_keyword_arguments_with_oblique,
+            "font = 1, size = 20, oblique = 12", (400, 20.0, false, 1, 4, 0),
+        parse_keyword_arguments_with_negative_oblique,
+            "font = 1, size = 20, oblique = -7.5", (400, 20.0, false, 1, 4, 0),
+        parse_keyword_arguments_with_vertical,
+            "font = 1, size = 20, vertical = true", (400, 20.0, false, 1, 4, 0),
+        parse_keyword_arguments_with_features,
+            "font = 1, size = 20, features = [\"tnum\", \"-liga\", \"salt=2\"]",
+            (400, 20.0, false, 1, 4, 0),
+    }
+
+    macro_rules! test_parse_arguments_error {
+        (
+            $(
+                $fn_ident:ident, $input:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let Err(error) = syn::parse_str::<Arguments>($input) else {
+                        panic!("expected error");
+                    };
+                    assert_eq!(error.to_string(), $expected);
+                }
+            )*
+        }
+    }
+
+    test_parse_arguments_error! {
+        parse_keyword_arguments_unknown_key,
+            "font = 1, size = 20, bold = true",
+            "expected one of: `font`, `weight`, `size`, `hint`, `positions`, `bit_depth`, \
+            `charmap`, `compress`, `subpixel`, `outline`, `oblique`, `vertical`, \
+            `features`, `sources`; found `bold`",
+        parse_keyword_arguments_duplicate_key,
+            "font = 1, size = 20, size = 30",
+            "duplicate argument `size`",
+        parse_keyword_arguments_missing_size,
+            "font = 1, weight = 500",
+            "missing argument `size`",
+        parse_keyword_arguments_weight_out_of_range,
+            "font = code(100), size = 20, weight = 800",
+            "expected number between `100` and `700`, found `800`",
+        parse_file_not_found,
+            "file(\"fonts/MPLUS3.ttf\"), 400, 20, false, 1, 4",
+            "failed to read font file `fonts/MPLUS3.ttf`: No such file or directory (os error 2)",
+        parse_file_not_a_font,
+            "file(\"fonts/LICENSE\"), 400, 20, false, 1, 4",
+            "expected TrueType or OpenType font file, found `fonts/LICENSE`",
+        parse_file_without_width_axis,
+            "file(\"fonts/MPLUS1[wght].ttf\", 100), 400, 20, false, 1, 4",
+            "font file `fonts/MPLUS1[wght].ttf` has no `wdth` axis",
+        parse_file_width_out_of_range,
+            "file(\"fonts/MPLUSCodeLatin[wdth,wght].ttf\", 150), 400, 20, false, 1, 4",
+            "expected number between `100` and `125`, found `150`",
+        parse_fallback_weight_out_of_range,
+            "fallback(code(100), 2), 800, 20, false, 1, 4",
+            "expected number between `100` and `700`, found `800`",
+        parse_fallback_with_one_font,
+            "fallback(2), 400, 20, false, 1, 4",
+            "expected at least 2 arguments, found 1",
+        parse_fallback_nested,
+            "fallback(2, fallback(1, 2)), 400, 20, false, 1, 4",
+            "remove the nested `fallback`",
+        parse_file_weight_out_of_range,
+            "font = file(\"fonts/MPLUSCodeLatin[wdth,wght].ttf\"), size = 20, weight = 800",
+            "expected number between `100` and `700`, found `800`",
+        parse_keyword_arguments_unknown_charmap,
+            "font = 1, size = 20, charmap = trie",
+            "expected identifier `tree` or `table`, found `trie`",
+        parse_keyword_arguments_unknown_compress,
+            "font = 1, size = 20, compress = lz4",
+            "expected identifier `none` or `rle`, found `lz4`",
+        parse_keyword_arguments_unknown_subpixel,
+            "font = 1, size = 20, subpixel = vrgb",
+            "expected one of: `none`, `rgb`, `bgr`; found `vrgb`",
+        parse_keyword_arguments_outline_out_of_range,
+            "font = 1, size = 20, outline = 9",
+            "expected number between `0` and `8`, found `9`",
+        parse_keyword_arguments_oblique_out_of_range,
+            "font = 1, size = 20, oblique = 45",
+            "expected number between `-30` and `30`, found `45`",
+        parse_keyword_arguments_oblique_not_a_number,
+            "font = 1, size = 20, oblique = \"12\"",
+            "expected integer or floating point literal",
+        parse_keyword_arguments_vertical_not_a_bool,
+            "font = 1, size = 20, vertical = 1",
+            "expected boolean literal",
+        parse_keyword_arguments_features_not_a_slice,
+            "font = 1, size = 20, features = \"tnum\"",
+            "expected slice literal expression",
+        parse_keyword_arguments_features_not_a_string,
+            "font = 1, size = 20, features = [tnum]",
+            "expected string literal",
+        parse_keyword_arguments_features_short_tag,
+            "font = 1, size = 20, features = [\"-tnm\"]",
+            "expected feature tag of 4 letters or digits, found `tnm`",
+        parse_keyword_arguments_features_invalid_tag,
+            "font = 1, size = 20, features = [\"ss-1\"]",
+            "expected feature tag of 4 letters or digits, found `ss-1`",
+        parse_keyword_arguments_features_invalid_value,
+            "font = 1, size = 20, features = [\"salt=x\"]",
+            "expected number between `0` and `65535`, found `x`",
+        parse_keyword_arguments_features_duplicate,
+            "font = 1, size = 20, features = [\"kern\", \"-kern\"]",
+            "duplicate feature `kern`",
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::ToTokens;
+
+use crate::bitmap::{Glyph, Image};
+use crate::charmap::{self, CharmapEntry};
+use crate::font::FontMetrics;
+
+const MAGIC: [u8; 4] = *b"MPLF";
+const VERSION: u16 = 2;
+
+const HEADER_SIZE: usize = 72;
+const ENTRY_SIZE: usize = 28;
+const COMPOSITION_SIZE: usize = 16;
+const KERNING_SIZE: usize = 12;
+const GLYPH_SIZE: usize = 16;
+const IMAGE_SIZE: usize = 20;
+
+pub struct BitmapFontBlob {
+    pub entries: BTreeMap<String, CharmapEntry>,
+    pub notdef: CharmapEntry,
+    pub positions: u8,
+    pub bit_depth: u8,
+    pub size: f32,
+    pub metrics: FontMetrics,
+}
+
+struct Writer {
+    offset: usize,
+    heap: Vec<u8>,
+    strings: BTreeMap<String, u32>,
+}
+
+impl BitmapFontBlob {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let Self {
+            entries,
+            notdef,
+            positions,
+            bit_depth,
+            size,
+            metrics,
+        } = self;
+
+        let entry_count = entries.len();
+        let compositions = charmap::compositions(entries.values());
+        let compositions_offset = HEADER_SIZE + (entry_count + 1) * ENTRY_SIZE;
+        let max_key_chars = entries.keys().map(|key| key.chars().count()).max();
+        let max_key_chars = max_key_chars.unwrap_or_default();
+        let mut bytes = Vec::with_capacity(HEADER_SIZE);
+        bytes.extend(MAGIC);
+        bytes.extend(VERSION.to_le_bytes());
+        bytes.push(*bit_depth);
+        bytes.push(*positions);
+        for value in [
+            metrics.top,
+            metrics.ascender,
+            metrics.cap_height,
+            metrics.x_height,
+            0.0,
+            metrics.descender,
+            metrics.bottom,
+            metrics.underline_offset,
+            metrics.stroke_size,
+            metrics.strikethrough_offset,
+            metrics.stroke_size,
+        ] {
+            bytes.extend((size * value).to_le_bytes());
+        }
+
+        bytes.extend(to_u32(entry_count).to_le_bytes());
+        bytes.extend(to_u32(HEADER_SIZE).to_le_bytes());
+        bytes.extend(to_u16(max_key_chars).to_le_bytes());
+        bytes.extend(0u16.to_le_bytes());
+        bytes.extend(to_u32(compositions.len()).to_le_bytes());
+        bytes.extend(to_u32(compositions_offset).to_le_bytes());
+        debug_assert_eq!(HEADER_SIZE, bytes.len(), "expected header of fixed size");
+
+        let mut writer = Writer {
+            offset: compositions_offset + compositions.len() * COMPOSITION_SIZE,
+            heap: Vec::new(),
+            strings: BTreeMap::new(),
+        };
+        for entry in entries.values().chain([notdef]) {
+            bytes.extend(writer.entry(entry));
+        }
+
+        for (decomposed, key) in &compositions {
+            bytes.extend(writer.string(decomposed).to_le_bytes());
+            bytes.extend(to_u32(decomposed.len()).to_le_bytes());
+            bytes.extend(writer.string(key).to_le_bytes());
+            bytes.extend(to_u32(key.len()).to_le_bytes());
+        }
+
+        debug_assert_eq!(writer.offset, bytes.len(), "expected tables of fixed size");
+        bytes.extend(writer.heap);
+
+        bytes
+    }
+}
+
+impl ToTokens for BitmapFontBlob {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let bytes = Literal::byte_string(&self.to_bytes());
+
+        bytes.to_tokens(tokens);
+    }
+}
+
+impl Writer {
+    fn position(&self) -> u32 {
+        to_u32(self.offset + self.heap.len())
+    }
+
+    fn entry(&mut self, entry: &CharmapEntry) -> Vec<u8> {
+        let CharmapEntry {
+            key,
+            advance_chars,
+            advance_width_to,
+            advance_width,
+            advance_height: _,
+            glyphs,
+        } = entry;
+
+        let key_offset = self.string(key);
+        let key_offsets: Vec<_> = advance_width_to
+            .keys()
+            .map(|key| self.string(key))
+            .collect();
+        let kerning_offset = self.position();
+        for ((key, value), key_offset) in advance_width_to.iter().zip(key_offsets) {
+            self.heap.extend(key_offset.to_le_bytes());
+            self.heap.extend(to_u32(key.len()).to_le_bytes());
+            self.heap.extend(value.to_le_bytes());
+        }
+
+        debug_assert_eq!(
+            advance_width_to.len() * KERNING_SIZE,
+            (self.position() - kerning_offset) as usize,
+            "expected table entries of fixed size"
+        );
+
+        let image_offsets: Vec<_> = glyphs.0.iter().map(|glyph| self.images(glyph)).collect();
+        let glyph_offset = self.position();
+        for (glyph, image_offset) in glyphs.0.iter().zip(image_offsets) {
+            let Glyph {
+                x_offset,
+                y_offset,
+                id,
+                images,
+                ..
+            } = glyph;
+
+            self.heap.extend(id.to_le_bytes());
+            self.heap.extend(to_u16(images.0.len()).to_le_bytes());
+            self.heap.extend(x_offset.to_le_bytes());
+            self.heap.extend(y_offset.to_le_bytes());
+            self.heap.extend(image_offset.to_le_bytes());
+        }
+
+        debug_assert_eq!(
+            glyphs.0.len() * GLYPH_SIZE,
+            (self.position() - glyph_offset) as usize,
+            "expected table entries of fixed size"
+        );
+
+        let mut record = Vec::with_capacity(ENTRY_SIZE);
+        record.extend(key_offset.to_le_bytes());
+        record.extend(to_u16(key.len()).to_le_bytes());
+        record.extend(to_u16(*advance_chars).to_le_bytes());
+        record.extend(advance_width.to_le_bytes());
+        record.extend(kerning_offset.to_le_bytes());
+        record.extend(to_u32(advance_width_to.len()).to_le_bytes());
+        record.extend(glyph_offset.to_le_bytes());
+        record.extend(to_u16(glyphs.0.len()).to_le_bytes());
+        record.extend(0u16.to_le_bytes());
+        debug_assert_eq!(
+            ENTRY_SIZE,
+            record.len(),
+            "expected table entry of fixed size"
+        );
+
+        record
+    }
+
+    fn images(&mut self, glyph: &Glyph) -> u32 {
+        let images = &glyph.images.0;
+        let data_offsets: Vec<_> = images
+            .iter()
+            .map(|image| {
+                let data_offset = self.position();
+                self.heap.extend(&image.data);
+
+                data_offset
+            })
+            .collect();
+        let image_offset = self.position();
+        for (image, data_offset) in images.iter().zip(data_offsets) {
+            let Image {
+                left,
+                top,
+                width,
+                data,
+                ..
+            } = image;
+
+            self.heap.extend(left.to_le_bytes());
+            self.heap.extend(top.to_le_bytes());
+            self.heap.extend(width.to_le_bytes());
+            self.heap.extend(data_offset.to_le_bytes());
+            self.heap.extend(to_u32(data.len()).to_le_bytes());
+        }
+
+        debug_assert_eq!(
+            images.len() * IMAGE_SIZE,
+            (self.position() - image_offset) as usize,
+            "expected table entries of fixed size"
+        );
+
+        image_offset
+    }
+
+    fn string(&mut self, string: &str) -> u32 {
+        if let Some(offset) = self.strings.get(string) {
+            return *offset;
+        }
+
+        let offset = self.position();
+        self.heap.extend(string.as_bytes());
+        self.strings.insert(string.to_owned(), offset);
+
+        offset
+    }
+}
+
+fn to_u16(value: usize) -> u16 {
+    value.try_into().expect("expected value to fit in `u16`")
+}
+
+fn to_u32(value: usize) -> u32 {
+    value.try_into().expect("expected value to fit in `u32`")
+}
@@ -1,3 +1,16 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+
+const LCD_FILTER: [u32; 5] = [8, 77, 86, 77, 8];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Subpixel {
+    None,
+    Rgb,
+    Bgr,
+}
+
 pub fn quantize(image_data: &[u8], image_width: u32, bit_depth: u8) -> Vec<u8> {
     let pixels_per_byte = match bit_depth {
         1 => 8,
@@ -53,6 +66,34 @@ pub fn quantize(image_data: &[u8], image_width: u32, bit_depth: u8) -> Vec<u8> {
     bytes
 }
 
+pub fn filter(image_data: &[u8], image_width: u32, left: i32) -> (i32, u32, Vec<u8>) {
+    let radius = LCD_FILTER.len() as i32 / 2;
+    let start = (left - radius).div_euclid(3);
+    let end = (left + image_width as i32 + radius + 2).div_euclid(3);
+    let width = (end - start) as u32;
+    let mut bytes = Vec::new();
+    for row_data in image_data.chunks_exact(image_width as usize) {
+        for index in 3 * start..3 * end {
+            let sum = LCD_FILTER
+                .iter()
+                .zip(index - radius..)
+                .fold(0, |sum, (weight, index)| {
+                    let value = usize::try_from(index - left)
+                        .ok()
+                        .and_then(|index| row_data.get(index))
+                        .copied()
+                        .unwrap_or_default();
+
+                    sum + weight * u32::from(value)
+                });
+
+            bytes.push(((sum + 128) >> 8) as u8);
+        }
+    }
+
+    (start, width, bytes)
+}
+
 const fn downsample(value: u8, divisor: u8) -> u8 {
     const SHIFT: usize = 23;
     const CONST_0_5: i32 = 1 << (SHIFT - 1);
@@ -66,6 +107,38 @@ const fn downsample(value: u8, divisor: u8) -> u8 {
     (result >> SHIFT) as u8
 }
 
+impl Parse for Subpixel {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "none" => Ok(Self::None),
+            "rgb" => Ok(Self::Rgb),
+            "bgr" => Ok(Self::Bgr),
+            name => {
+                let message = format!("expected one of: `none`, `rgb`, `bgr`; found `{name}`");
+
+                Err(syn::Error::new(ident.span(), message))
+            }
+        }
+    }
+}
+
+impl ToTokens for Subpixel {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let subpixel = match self {
+            Self::None => quote!(::core::option::Option::None),
+            Self::Rgb => quote! {
+                ::core::option::Option::Some(::mplusfonts::color::SubpixelOrder::Rgb)
+            },
+            Self::Bgr => quote! {
+                ::core::option::Option::Some(::mplusfonts::color::SubpixelOrder::Bgr)
+            },
+        };
+
+        tokens.extend(subpixel);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,4 +183,41 @@ mod tests {
         downsample_255_no_resample, 255, 1, 255,
         downsample_255_upsample, 255, 0, 255,
     }
+
+    macro_rules! test_filter {
+        (
+            $(
+                $fn_ident:ident, $image_data:expr, $image_width:expr, $left:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let result = filter(&$image_data, $image_width, $left);
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_filter! {
+        filter_single_subpixel_at_0,
+            [255], 1, 0,
+            (-1, 2, Vec::from([0, 8, 77, 86, 77, 8])),
+        filter_single_subpixel_at_1,
+            [255], 1, 1,
+            (-1, 3, Vec::from([0, 0, 8, 77, 86, 77, 8, 0, 0])),
+        filter_single_subpixel_at_2,
+            [255], 1, 2,
+            (0, 2, Vec::from([8, 77, 86, 77, 8, 0])),
+        filter_full_pixel_at_3,
+            [255, 255, 255], 3, 3,
+            (0, 3, Vec::from([0, 8, 85, 170, 239, 170, 85, 8, 0])),
+        filter_two_rows_at_minus_3,
+            [0, 128, 0, 255, 255, 255], 3, -3,
+            (-2, 3, Vec::from([
+                0, 0, 4, 39, 43, 39, 4, 0, 0,
+                0, 8, 85, 170, 239, 170, 85, 8, 0,
+            ])),
+    }
 }
@@ -2,8 +2,8 @@ use std::collections::BTreeMap;
 use std::collections::btree_map::Entry;
 use std::sync::RwLock;
 
-use crate::mplus::bitmap::{Glyph, GlyphList};
-use crate::mplus::charmap::CharmapEntry;
+use crate::bitmap::{Glyph, GlyphList};
+use crate::charmap::CharmapEntry;
 
 #[derive(Clone, Copy)]
 pub struct CharDictionary<'a>(&'a RwLock<BTreeMap<String, CharmapEntry>>);
@@ -34,6 +34,7 @@ impl<'a> CharDictionary<'a> {
             advance_chars: key.chars().count(),
             advance_width_to: BTreeMap::new(),
             advance_width: glyphs.iter().map(|glyph| glyph.advance_width).sum(),
+            advance_height: glyphs.first().map_or(0.0, |glyph| glyph.advance_height),
             glyphs: GlyphList(glyphs),
         });
     }
@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+
+use crate::bitmap::{Subpixel, image_statics};
+use crate::charmap::{Charmap, CharmapEntry};
+use crate::font::FontMetrics;
+
+pub struct BitmapFont {
+    pub charmap: Charmap,
+    pub notdef: CharmapEntry,
+    pub positions: u8,
+    pub bit_depth: u8,
+    pub subpixel: Subpixel,
+    pub size: f32,
+    pub metrics: FontMetrics,
+}
+
+impl ToTokens for BitmapFont {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            charmap,
+            notdef,
+            positions,
+            bit_depth,
+            subpixel,
+            size,
+            metrics,
+        } = self;
+
+        let positions = *positions as usize;
+        let params = match bit_depth {
+            1 => quote!(::embedded_graphics::pixelcolor::BinaryColor, #positions),
+            2 => quote!(::embedded_graphics::pixelcolor::Gray2, #positions),
+            4 => quote!(::embedded_graphics::pixelcolor::Gray4, #positions),
+            8 => quote!(::embedded_graphics::pixelcolor::Gray8, #positions),
+            x => panic!("expected one of: `1`, `2`, `4`, `8`; found: `{x}`"),
+        };
+        let entries = charmap.entries().into_iter().chain([notdef]);
+        let images = image_statics(entries.flat_map(CharmapEntry::images));
+        let compositions = compositions_tokens(&charmap.compositions());
+        let charmap = charmap_tokens(charmap, notdef, &params);
+        let underline = underline_tokens(*size, metrics);
+        let strikethrough = strikethrough_tokens(*size, metrics);
+        let metrics = metrics_tokens(*size, metrics);
+        let font = quote! {
+            {
+                #images
+
+                ::mplusfonts::BitmapFont::<#params> {
+                    charmap: #charmap,
+                    compositions: #compositions,
+                    metrics: #metrics,
+                    underline: #underline,
+                    strikethrough: #strikethrough,
+                    subpixel: #subpixel,
+                }
+            }
+        };
+
+        tokens.extend(font);
+    }
+}
+
+fn charmap_tokens(charmap: &Charmap, notdef: &CharmapEntry, params: &impl ToTokens) -> TokenStream {
+    let (payload, charmap) = match charmap {
+        Charmap::Tree(payload, charmap) => (payload, charmap),
+        Charmap::Table(entries) => return table_tokens(entries, notdef, params),
+    };
+    let payload = payload.as_ref().unwrap_or(notdef);
+    let leaf = quote! {
+        ::mplusfonts::Charmap::Leaf(#payload)
+    };
+    if charmap.is_empty() {
+        return leaf;
+    }
+
+    let static_ref = |charmap| {
+        quote!(const {
+            const DATA: ::mplusfonts::Charmap<#params> = #charmap;
+
+            &DATA
+        })
+    };
+    let map = charmap.iter().map(|(key, charmap)| {
+        let charmap = charmap_tokens(charmap, payload, params);
+        let value = static_ref(charmap);
+        quote!(#key => #value)
+    });
+    let default = static_ref(leaf);
+    let branch = quote! {
+        ::mplusfonts::Charmap::Branch(
+            |key| match key {
+                #(#map,)*
+                _ => #default
+            }
+        )
+    };
+
+    branch
+}
+
+fn table_tokens(
+    entries: &BTreeMap<String, CharmapEntry>,
+    notdef: &CharmapEntry,
+    params: &impl ToTokens,
+) -> TokenStream {
+    let length = entries.len();
+    let chars = entries
+        .keys()
+        .map(|key| key.chars().next().unwrap_or_default());
+    let entries = entries.values();
+    let table = quote! {
+        ::mplusfonts::Charmap::Table(
+            ::mplusfonts::CharmapTable {
+                chars: &[#(#chars,)*],
+                entries: const {
+                    const DATA: [::mplusfonts::CharmapEntry<#params>; #length] = [#(#entries,)*];
+
+                    &DATA
+                },
+                notdef: #notdef,
+            }
+        )
+    };
+
+    table
+}
+
+fn compositions_tokens(compositions: &BTreeMap<String, String>) -> TokenStream {
+    let decomposed = compositions.keys();
+    let keys = compositions.values();
+    let table = quote! {
+        ::mplusfonts::CompositionTable {
+            decomposed: &[#(#decomposed,)*],
+            keys: &[#(#keys,)*],
+        }
+    };
+
+    table
+}
+
+fn metrics_tokens(size: f32, metrics: &FontMetrics) -> TokenStream {
+    let top = size * metrics.top;
+    let ascender = size * metrics.ascender;
+    let cap_height = size * metrics.cap_height;
+    let x_height = size * metrics.x_height;
+    let baseline = 0f32;
+    let descender = size * metrics.descender;
+    let bottom = size * metrics.bottom;
+    let metrics = quote! {
+        ::mplusfonts::BitmapFontMetrics {
+            top: #top,
+            ascender: #ascender,
+            cap_height: #cap_height,
+            x_height: #x_height,
+            baseline: #baseline,
+            descender: #descender,
+            bottom: #bottom,
+        }
+    };
+
+    metrics
+}
+
+fn underline_tokens(size: f32, metrics: &FontMetrics) -> TokenStream {
+    let offset = size * metrics.underline_offset;
+    let height = size * metrics.stroke_size;
+    let dimensions = quote! {
+        ::mplusfonts::DecorationDimensions {
+            offset: #offset,
+            height: #height,
+        }
+    };
+
+    dimensions
+}
+
+fn strikethrough_tokens(size: f32, metrics: &FontMetrics) -> TokenStream {
+    let offset = size * metrics.strikethrough_offset;
+    let height = size * metrics.stroke_size;
+    let dimensions = quote! {
+        ::mplusfonts::DecorationDimensions {
+            offset: #offset,
+            height: #height,
+        }
+    };
+
+    dimensions
+}
@@ -0,0 +1,189 @@
+mod offsets;
+mod rendering;
+mod spacing;
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::thread;
+
+use swash::GlyphMetrics;
+use swash::scale::image::Image as ScaledImage;
+use swash::scale::{Render, Scaler, Source};
+use swash::zeno::{Join, Stroke, Vector};
+
+use crate::bitmap::color::{self, Subpixel};
+use crate::bitmap::units::Halfwidth;
+use crate::bitmap::{Compression, Glyph, Image, ImageList};
+
+pub use offsets::GlyphOffsets;
+pub use rendering::GlyphRendering;
+pub use spacing::GlyphSpacing;
+
+impl GlyphOffsets {
+    pub fn scale(
+        &self,
+        scalers: &mut [Scaler],
+        rendering: &GlyphRendering,
+        glyph_metrics: &GlyphMetrics,
+        glyph_spacing: &GlyphSpacing,
+    ) -> Glyph {
+        let positions = u8::try_from(scalers.len()).expect("expected one scaler per position");
+        let GlyphRendering {
+            bit_depth,
+            subpixel,
+            outline,
+            vertical,
+            ..
+        } = *rendering;
+        let x_scale = rendering.x_scale();
+        let transform = rendering.transform();
+        let advance_width = glyph_metrics.advance_width(self.id);
+        let advance_height = glyph_metrics.advance_height(self.id);
+        let new_advance_width = glyph_spacing.halfwidths(advance_width);
+        let centering_offset = (new_advance_width - advance_width) / 2.0;
+        let x_offset = glyph_spacing.compensate(self.x_offset);
+        let y_offset = self.y_offset;
+        let is_repeating = glyph_spacing.is_code || advance_width == advance_height || self.id == 0;
+        let images = if let Halfwidth::Floor(_) | Halfwidth::Ceil = glyph_spacing.halfwidth {
+            if !self.is_overlay || self.x_offset < 0.0 {
+                let length = if is_repeating { 1 } else { positions };
+                let images = Mutex::new(BTreeMap::new());
+                thread::scope(|scope| {
+                    let images = &images;
+                    (0..length).zip(scalers).for_each(|(index, scaler)| {
+                        let x_offset = x_offset.fract() + f32::from(index) / f32::from(length);
+                        let y_offset = y_offset.fract();
+                        scope.spawn(move || {
+                            let offset = Vector::new(x_offset * x_scale, y_offset);
+                            let image = Render::new(&[Source::Outline])
+                                .transform(transform)
+                                .offset(offset)
+                                .render(scaler, self.id)
+                                .expect("expected glyph outline");
+
+                            if image.data.is_empty() {
+                                return;
+                            }
+
+                            let outline = (outline > 0).then(|| {
+                                let mut stroke = Stroke::new(f32::from(outline) * 2.0);
+                                stroke.join(Join::Round);
+
+                                let stroke_image = Render::new(&[Source::Outline])
+                                    .style(stroke)
+                                    .transform(transform)
+                                    .offset(offset)
+                                    .render(scaler, self.id)
+                                    .expect("expected glyph outline");
+
+                                let (left, top, width, data) = union(&image, &stroke_image);
+                                let left = left.saturating_add_unsigned(centering_offset as u32);
+                                let data = color::quantize(&data, width, bit_depth);
+
+                                Image {
+                                    left,
+                                    top,
+                                    width,
+                                    data,
+                                    compression: Compression::None,
+                                    static_index: None,
+                                }
+                            });
+
+                            let left = image.placement.left;
+                            let top = image.placement.top;
+                            let width = image.placement.width;
+                            let (left, width, data) = match subpixel {
+                                Subpixel::None => (left, width, image.data),
+                                Subpixel::Rgb | Subpixel::Bgr => {
+                                    let (left, width, data) =
+                                        color::filter(&image.data, width, left);
+
+                                    (left, 3 * width, data)
+                                }
+                            };
+                            let left = left.saturating_add_unsigned(centering_offset as u32);
+                            let data = color::quantize(&data, width, bit_depth);
+                            let image = Image {
+                                left,
+                                top,
+                                width,
+                                data,
+                                compression: Compression::None,
+                                static_index: None,
+                            };
+
+                            images
+                                .lock()
+                                .expect("expected no-poison lock on images")
+                                .insert(index, (image, outline));
+                        });
+                    });
+                });
+
+                images
+                    .into_inner()
+                    .expect("expected no-poison lock on images")
+                    .into_values()
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        } else {
+            Vec::new()
+        };
+
+        let (images, outlines): (_, Vec<_>) = images.into_iter().unzip();
+        let outlines = outlines.into_iter().flatten().collect();
+
+        Glyph {
+            x_offset: x_offset - x_offset.fract(),
+            y_offset: y_offset - y_offset.fract(),
+            positions,
+            bit_depth,
+            id: self.id,
+            advance_width: new_advance_width,
+            advance_height: if vertical { advance_height } else { 0.0 },
+            images: ImageList(images),
+            outlines: ImageList(outlines),
+        }
+    }
+}
+
+/// Combines a glyph image with the image of its stroke, taking the greater of the two coverage
+/// values for each pixel in the area that both images cover.
+fn union(image: &ScaledImage, stroke_image: &ScaledImage) -> (i32, i32, u32, Vec<u8>) {
+    let [first, second] = [image.placement, stroke_image.placement];
+    let left = i32::min(first.left, second.left);
+    let top = i32::max(first.top, second.top);
+    let right = i32::max(
+        first.left.saturating_add_unsigned(first.width),
+        second.left.saturating_add_unsigned(second.width),
+    );
+    let bottom = i32::min(
+        first.top.saturating_sub_unsigned(first.height),
+        second.top.saturating_sub_unsigned(second.height),
+    );
+
+    let width = right.abs_diff(left);
+    let height = top.abs_diff(bottom);
+    let mut data = vec![0; width as usize * height as usize];
+    for image in [image, stroke_image] {
+        let placement = image.placement;
+        if placement.width == 0 {
+            continue;
+        }
+
+        let x = placement.left.abs_diff(left) as usize;
+        let y = top.abs_diff(placement.top) as usize;
+        let rows = image.data.chunks_exact(placement.width as usize);
+        for (row, values) in rows.enumerate() {
+            let offset = (y + row) * width as usize + x;
+            for (value, result) in values.iter().zip(&mut data[offset..]) {
+                *result = u8::max(*result, *value);
+            }
+        }
+    }
+
+    (left, top, width, data)
+}
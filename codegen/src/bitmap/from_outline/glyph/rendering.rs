@@ -0,0 +1,86 @@
+use swash::zeno::Transform;
+
+use crate::Arguments;
+use crate::bitmap::color::Subpixel;
+
+#[derive(Clone, Copy)]
+pub struct GlyphRendering {
+    pub bit_depth: u8,
+    pub subpixel: Subpixel,
+    pub outline: u8,
+    pub oblique: f32,
+    pub vertical: bool,
+}
+
+impl GlyphRendering {
+    pub fn from_args(args: &Arguments) -> Self {
+        Self {
+            bit_depth: args.bit_depth,
+            subpixel: args.subpixel,
+            outline: args.outline,
+            oblique: args.oblique,
+            vertical: args.vertical,
+        }
+    }
+
+    pub fn x_scale(&self) -> f32 {
+        match self.subpixel {
+            Subpixel::None => 1.0,
+            Subpixel::Rgb | Subpixel::Bgr => 3.0,
+        }
+    }
+
+    pub fn transform(&self) -> Option<Transform> {
+        let x_scale = self.x_scale();
+        let shear = self.oblique.to_radians().tan();
+        let is_identity = x_scale == 1.0 && shear == 0.0;
+
+        (!is_identity).then(|| Transform::new(x_scale, 0.0, x_scale * shear, 1.0, 0.0, 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swash::zeno::Point;
+
+    use super::*;
+
+    macro_rules! test_transform {
+        (
+            $(
+                $fn_ident:ident, $subpixel:expr, $oblique:expr, $point:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let rendering = GlyphRendering {
+                        bit_depth: 4,
+                        subpixel: $subpixel,
+                        outline: 0,
+                        oblique: $oblique,
+                        vertical: false,
+                    };
+                    let result = rendering
+                        .transform()
+                        .map(|transform| transform.transform_point($point));
+                    let result = result.map(|Point { x, y }| ((x * 100.0).round(), y));
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_transform! {
+        transform_none,
+            Subpixel::None, 0.0, Point::new(1.0, 10.0), None,
+        transform_oblique,
+            Subpixel::None, 45.0, Point::new(1.0, 10.0), Some((1100.0, 10.0)),
+        transform_oblique_below_baseline,
+            Subpixel::None, 45.0, Point::new(1.0, -2.0), Some((-100.0, -2.0)),
+        transform_subpixel,
+            Subpixel::Rgb, 0.0, Point::new(1.0, 10.0), Some((300.0, 10.0)),
+        transform_subpixel_oblique,
+            Subpixel::Bgr, 45.0, Point::new(1.0, 10.0), Some((3300.0, 10.0)),
+    }
+}
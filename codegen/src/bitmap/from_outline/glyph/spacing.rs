@@ -1,5 +1,5 @@
-use crate::mplus::Font;
-use crate::mplus::bitmap::units::Halfwidth;
+use crate::Font;
+use crate::bitmap::units::Halfwidth;
 
 pub struct GlyphSpacing {
     pub halfwidth: Halfwidth,
@@ -9,7 +9,7 @@ pub struct GlyphSpacing {
 impl GlyphSpacing {
     pub fn from_font(font: &Font, pixels_per_em: f32) -> Self {
         let halfwidth = Halfwidth::from_font(font, pixels_per_em);
-        let is_code = matches!(font, Font::MPLUSCode { .. });
+        let is_code = matches!(font.primary(), Font::MPLUSCode { .. });
 
         Self { halfwidth, is_code }
     }
@@ -5,48 +5,27 @@ use std::collections::BTreeMap;
 use std::sync::RwLock;
 use std::{iter, thread};
 
-use glyph::{GlyphOffsets, GlyphSpacing};
+use glyph::{GlyphOffsets, GlyphRendering, GlyphSpacing};
 use string::StringRefList;
 use swash::scale::ScaleContext;
 use swash::shape::ShapeContext;
 
-use crate::mplus::Arguments;
-use crate::mplus::charmap::CharmapEntry;
-use crate::mplus::font::{Font, FontWidth};
+use crate::Arguments;
+use crate::charmap::CharmapEntry;
+use crate::font::{Font, FontFeature};
 
 use super::CharDictionary;
 
-pub fn render(args: &Arguments, is_fallback: bool) -> BTreeMap<String, CharmapEntry> {
+pub fn render(args: &Arguments, layer: usize) -> BTreeMap<String, CharmapEntry> {
     let entries = BTreeMap::new();
-    let font = args.font.value();
-    let font_ref = font.as_ref(is_fallback);
-    let is_code = matches!(font, Font::MPLUSCode { .. });
-    if !is_code && is_fallback {
-        return entries;
-    }
-
-    let mut coords = Vec::new();
-    let units = args.weight.into_value();
-    let weight_axis = font_ref
-        .variations()
-        .find_by_tag(swash::tag_from_str_lossy("wght"))
-        .expect("expected font weight axis");
-
-    coords.push(weight_axis.normalize(units.into()));
-
-    if let Font::MPLUSCode { variable, .. } = font {
-        let (.., FontWidth(units)) = *variable;
-        if !is_fallback {
-            let width_axis = font_ref
-                .variations()
-                .find_by_tag(swash::tag_from_str_lossy("wdth"))
-                .expect("expected font width axis");
-
-            coords.push(width_axis.normalize(units.into()));
-        }
-    }
-
-    let pixels_per_em = args.size.into_value();
+    let font = &args.font;
+    let font_ref = font.as_ref(layer);
+    let is_code = matches!(font.primary(), Font::MPLUSCode { .. });
+    let is_fallback = layer > 0;
+    let is_mpluscode = font.is_mpluscode(layer);
+    let coords = font.coords(args.weight, layer);
+
+    let pixels_per_em = args.size;
     let glyph_metrics = font_ref.glyph_metrics(&coords).scale(pixels_per_em);
     let glyph_spacing = GlyphSpacing::from_font(font, pixels_per_em);
 
@@ -54,17 +33,33 @@ pub fn render(args: &Arguments, is_fallback: bool) -> BTreeMap<String, CharmapEn
         .take(thread::available_parallelism().map(Into::into).unwrap_or(1))
         .collect();
 
+    let mut features = Vec::from([
+        FontFeature::new(b"liga", !is_fallback as u16),
+        FontFeature::new(b"vert", args.vertical as u16),
+        FontFeature::new(b"vrt2", args.vertical as u16),
+    ]);
+    for feature in args
+        .features
+        .iter()
+        .filter(|feature| !is_fallback || feature.tag != *b"liga")
+    {
+        match features.iter_mut().find(|other| other.tag == feature.tag) {
+            Some(other) => other.value = feature.value,
+            None => features.push(*feature),
+        }
+    }
+
     let shapers = contexts.iter_mut().map(|context| {
         context
             .builder(font_ref)
             .normalized_coords(&coords)
             .size(pixels_per_em)
-            .features(&[("liga", !is_fallback as u16)])
+            .features(features.iter().map(FontFeature::setting))
             .build()
     });
 
-    let positions = args.positions.into_value();
-    let bit_depth = args.bit_depth.into_value();
+    let positions = args.positions;
+    let glyph_rendering = GlyphRendering::from_args(args);
 
     let mut contexts: Vec<_> = iter::repeat_with(ScaleContext::new)
         .take(shapers.len() * positions as usize)
@@ -75,17 +70,18 @@ pub fn render(args: &Arguments, is_fallback: bool) -> BTreeMap<String, CharmapEn
             .builder(font_ref)
             .normalized_coords(&coords)
             .size(pixels_per_em)
-            .hint(args.hint.into_value())
+            .hint(args.hint)
             .build()
     });
 
     let mut scalers: Vec<_> = scalers.collect();
     let scalers = scalers.chunks_mut(positions as usize);
     let renders = scalers.map(|scalers| {
+        let glyph_rendering = &glyph_rendering;
         let glyph_metrics = &glyph_metrics;
         let glyph_spacing = &glyph_spacing;
         move |glyph_offsets: GlyphOffsets| {
-            glyph_offsets.scale(scalers, positions, bit_depth, glyph_metrics, glyph_spacing)
+            glyph_offsets.scale(scalers, glyph_rendering, glyph_metrics, glyph_spacing)
         }
     });
 
@@ -122,7 +118,14 @@ pub fn render(args: &Arguments, is_fallback: bool) -> BTreeMap<String, CharmapEn
             .zip(strings)
             .for_each(|((shaper, render), strings)| {
                 scope.spawn(move || {
-                    strings.shape_and_render(entries, shaper, render, glyph_spacing, is_fallback)
+                    strings.shape_and_render(
+                        entries,
+                        shaper,
+                        render,
+                        glyph_spacing,
+                        is_mpluscode,
+                        is_fallback,
+                    )
                 });
             });
     });
@@ -3,7 +3,7 @@ use std::iter;
 use swash::shape::Shaper;
 use swash::text::cluster::SourceRange;
 
-use crate::mplus::bitmap::{CharDictionary, CharDictionaryKey, Glyph};
+use crate::bitmap::{CharDictionary, CharDictionaryKey, Glyph};
 
 use super::glyph::{GlyphOffsets, GlyphSpacing};
 
@@ -16,6 +16,7 @@ impl StringRefList<'_> {
         mut shaper: Shaper,
         mut render: impl FnMut(GlyphOffsets) -> Glyph,
         glyph_spacing: &GlyphSpacing,
+        is_mpluscode: Option<bool>,
         is_fallback: bool,
     ) {
         let Self(strings) = self;
@@ -55,7 +56,9 @@ impl StringRefList<'_> {
                 advance_width += glyph.advance;
                 glyph_offsets.push({
                     let mut glyph_offsets = GlyphOffsets::from_glyph(glyph, is_overlay);
-                    glyph_offsets.patch(glyph_spacing.is_code && !is_fallback);
+                    if let Some(is_mpluscode) = is_mpluscode {
+                        glyph_offsets.patch(is_mpluscode);
+                    }
 
                     glyph_offsets
                 });
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use swash::GlyphId;
 
-use crate::mplus::bitmap::ImageList;
+use crate::bitmap::ImageList;
 
 pub struct Glyph {
     pub x_offset: f32,
@@ -11,7 +11,9 @@ pub struct Glyph {
     pub bit_depth: u8,
     pub id: GlyphId,
     pub advance_width: f32,
+    pub advance_height: f32,
     pub images: ImageList,
+    pub outlines: ImageList,
 }
 
 pub struct GlyphList(pub Vec<Glyph>);
@@ -28,7 +30,9 @@ impl ToTokens for GlyphList {
                 bit_depth,
                 id,
                 advance_width: _,
+                advance_height: _,
                 images,
+                outlines,
             } = glyph;
 
             let next_glyph = match previous_glyph.take() {
@@ -43,6 +47,7 @@ impl ToTokens for GlyphList {
                 ::mplusfonts::glyph::Glyph {
                     id: #id,
                     images: #images,
+                    outline: #outlines,
                     next: #next_glyph,
                 }
             };
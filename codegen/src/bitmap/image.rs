@@ -0,0 +1,217 @@
+use std::collections::{BTreeMap, HashMap};
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+
+use crate::bitmap::rle;
+
+pub struct Image {
+    pub left: i32,
+    pub top: i32,
+    pub width: u32,
+    pub data: Vec<u8>,
+    pub compression: Compression,
+    pub static_index: Option<usize>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Rle,
+}
+
+pub struct ImageList(pub Vec<Image>);
+
+impl Image {
+    pub fn compress(&mut self, compression: Compression, bit_depth: u8) {
+        if self.compression == compression {
+            return;
+        }
+
+        debug_assert!(
+            self.compression == Compression::None,
+            "expected uncompressed data"
+        );
+        self.data = match compression {
+            Compression::None => return,
+            Compression::Rle => rle::encode(&self.data, self.width, bit_depth),
+        };
+        self.compression = compression;
+    }
+
+    pub fn data_ident(&self) -> syn::Ident {
+        let index = self
+            .static_index
+            .expect("expected image to have a static index");
+
+        format_ident!("IMAGE_{index}")
+    }
+}
+
+impl ToTokens for Image {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            left,
+            top,
+            width,
+            compression,
+            ..
+        } = self;
+
+        let data = self.data_ident();
+        let image_raw = match compression {
+            Compression::None => quote! {
+                ::mplusfonts::image::ImageData::Raw(
+                    ::mplusfonts::image::ImageRaw::new(&#data, #width)
+                )
+            },
+            Compression::Rle => quote! {
+                ::mplusfonts::image::ImageData::Rle(
+                    ::mplusfonts::image::ImageRle::new(&#data, #width)
+                )
+            },
+        };
+        let offset = quote! {
+            ::embedded_graphics::geometry::Point::new(#left, #top)
+        };
+        let image = quote! {
+            ::mplusfonts::image::Image::new(#image_raw, #offset)
+        };
+
+        tokens.extend(image);
+    }
+}
+
+impl ToTokens for ImageList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self(vec) = self;
+        let image_set = match vec.as_slice() {
+            [] => quote! {
+                ::mplusfonts::image::ImageSet::Repeated(::mplusfonts::image::Image::NULL)
+            },
+            [image] => quote! {
+                ::mplusfonts::image::ImageSet::Repeated(#image)
+            },
+            _ => quote! {
+                ::mplusfonts::image::ImageSet::Array([#(#vec),*])
+            },
+        };
+
+        tokens.extend(image_set);
+    }
+}
+
+impl Parse for Compression {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "none" => Ok(Self::None),
+            "rle" => Ok(Self::Rle),
+            name => {
+                let message = format!("expected identifier `none` or `rle`, found `{name}`");
+
+                Err(syn::Error::new(ident.span(), message))
+            }
+        }
+    }
+}
+
+pub fn index_statics<'a>(images: impl IntoIterator<Item = &'a mut Image>) {
+    let mut indices = HashMap::new();
+    for image in images {
+        let next_index = indices.len();
+        let index = *indices.entry(image.data.clone()).or_insert(next_index);
+        image.static_index = Some(index);
+    }
+}
+
+pub fn image_statics<'a>(images: impl IntoIterator<Item = &'a Image>) -> TokenStream {
+    let mut statics = BTreeMap::new();
+    for image in images {
+        let index = image
+            .static_index
+            .expect("expected image to have a static index");
+        statics.entry(index).or_insert(image);
+    }
+
+    let statics = statics.into_values().map(|image| {
+        let ident = image.data_ident();
+        let data = &image.data;
+        let length = data.len();
+        let data = Literal::byte_string(data);
+        quote!(static #ident: [u8; #length] = *#data;)
+    });
+
+    quote!(#(#statics)*)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Arguments, CharSource, bitmap_font};
+
+    const PANGRAM: &str = "Sphinx of black quartz, bite the wax tadpole? Very good job! \
+        Efficient deflate toffee. 1 VA is not a watt.";
+    const KANJI: &str = "mplusfonts（エムプラスフォンツ）は、森下浩司によって\
+        デザインされているゴシック体の日本語フォントである。";
+    const RADICALS: &str = "⼀⼈⼝⼟⼤⼥⼦⼭⼯⼼一人口土大女子山工心";
+
+    macro_rules! test_image_statics {
+        (
+            $(
+                $fn_ident:ident, $input:expr, $strings:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let mut args: Arguments = syn::parse_str($input).expect("expected arguments");
+                    let strings = Vec::from($strings.map(String::from));
+                    args.sources.push(CharSource::Strings(strings));
+                    let font = bitmap_font(&mut args).expect("expected bitmap font");
+                    let entries = font.charmap.entries().into_iter().chain([&font.notdef]);
+                    let images: Vec<_> = entries.flat_map(|entry| entry.images()).collect();
+                    let total: usize = images.iter().map(|image| image.data.len()).sum();
+                    let statics = image_statics(images);
+                    let statics = syn::parse2::<syn::File>(statics).expect("expected items");
+                    let shared: usize = statics
+                        .items
+                        .iter()
+                        .map(|item| match item {
+                            syn::Item::Static(item_static) => match &*item_static.expr {
+                                syn::Expr::Unary(syn::ExprUnary { expr, .. }) => match &**expr {
+                                    syn::Expr::Lit(syn::ExprLit {
+                                        lit: syn::Lit::ByteStr(lit),
+                                        ..
+                                    }) => lit.value().len(),
+                                    _ => panic!("expected byte string literal"),
+                                },
+                                _ => panic!("expected dereference"),
+                            },
+                            _ => panic!("expected static item"),
+                        })
+                        .sum();
+                    assert_eq!((total, shared), $expected);
+                }
+            )*
+        }
+    }
+
+    test_image_statics! {
+        image_statics_pangram_1_480_x_height_7_true_4_4,
+            "1, 480, x_height(7), true, 4, 4", [PANGRAM], (5058, 5058),
+        image_statics_pangram_code_125_480_x_height_7_true_4_4,
+            "code(125), 480, x_height(7), true, 4, 4", [PANGRAM], (1094, 1094),
+        image_statics_pangram_1_480_x_height_7_true_4_1,
+            "1, 480, x_height(7), true, 4, 1", [PANGRAM], (1534, 1415),
+        image_statics_kanji_1_420_cap_height_10_true_1_4,
+            "1, 420, cap_height(10), true, 1, 4", [KANJI], (3448, 3448),
+        image_statics_kanji_2_420_cap_height_10_true_1_4,
+            "2, 420, cap_height(10), true, 1, 4", [KANJI], (3437, 3437),
+        image_statics_pangram_1_400_10_false_4_1,
+            "1, 400, 10, false, 4, 1", [PANGRAM], (1147, 1071),
+        image_statics_radicals_2_400_16_true_1_4,
+            "2, 400, 16, true, 1, 4", [RADICALS], (2016, 1040),
+    }
+}
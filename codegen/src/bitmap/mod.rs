@@ -1,13 +1,17 @@
+mod blob;
 mod color;
 mod dict;
 mod font;
 mod glyph;
 mod image;
+mod rle;
 mod units;
 
 pub mod from_outline;
 
+pub use blob::BitmapFontBlob;
+pub use color::Subpixel;
 pub use dict::{CharDictionary, CharDictionaryKey};
 pub use font::BitmapFont;
 pub use glyph::{Glyph, GlyphList};
-pub use image::{Image, ImageList};
+pub use image::{Compression, Image, ImageList, image_statics, index_statics};
@@ -0,0 +1,112 @@
+pub fn encode(image_data: &[u8], image_width: u32, bit_depth: u8) -> Vec<u8> {
+    let bits_per_row = image_width as usize * bit_depth as usize;
+    let bytes_per_row = bits_per_row.div_ceil(8);
+    let rows = image_data.chunks_exact(bytes_per_row.max(1));
+    let height = if bytes_per_row > 0 { rows.len() } else { 0 };
+    let height = u16::try_from(height).expect("expected image height to fit in `u16`");
+    let mask = 2u8.wrapping_pow(bit_depth.into()).wrapping_sub(1);
+    let values: Vec<_> = rows
+        .take(height as usize)
+        .flat_map(|row_data| {
+            (0..image_width as usize).map(move |x| {
+                let bit = x * bit_depth as usize;
+                let shift = 8 - bit_depth as usize - bit % 8;
+
+                (row_data[bit / 8] >> shift) & mask
+            })
+        })
+        .collect();
+
+    let mut bytes = Vec::from(height.to_le_bytes());
+    let mut literal = Vec::new();
+    let mut index = 0;
+    while let Some(&value) = values.get(index) {
+        let run = values[index..].iter().take(64);
+        let run = run.take_while(|other| **other == value).count();
+        let run_size = if value == 0 { 1 } else { 2 };
+        if run * bit_depth as usize > 8 * run_size {
+            push_literal(&mut bytes, &mut literal, bit_depth);
+            let control = (run - 1) as u8;
+            if value == 0 {
+                bytes.push(control);
+            } else {
+                bytes.push(0b0100_0000 | control);
+                bytes.push(value << (8 - bit_depth));
+            }
+
+            index += run;
+        } else {
+            literal.push(value);
+            if literal.len() == 128 {
+                push_literal(&mut bytes, &mut literal, bit_depth);
+            }
+
+            index += 1;
+        }
+    }
+
+    push_literal(&mut bytes, &mut literal, bit_depth);
+
+    bytes
+}
+
+fn push_literal(bytes: &mut Vec<u8>, literal: &mut Vec<u8>, bit_depth: u8) {
+    if literal.is_empty() {
+        return;
+    }
+
+    bytes.push(0b1000_0000 | (literal.len() - 1) as u8);
+    for chunk in literal.chunks(8 / bit_depth as usize) {
+        let chunk = chunk.iter().zip(1u8..);
+        let byte = chunk.fold(0u8, |byte, (value, factor)| {
+            byte | (value << (8 - bit_depth * factor))
+        });
+
+        bytes.push(byte);
+    }
+
+    literal.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_encode {
+        (
+            $(
+                $fn_ident:ident, $image_data:expr, $image_width:expr, $bit_depth:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let result = encode(&$image_data, $image_width, $bit_depth);
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_encode! {
+        encode_empty, [], 0, 4, [0, 0],
+        encode_zeros_4bpp, [0x00; 8], 4, 4, [4, 0, 0b0000_1111],
+        encode_literal_4bpp, [0x12, 0x34], 2, 4, [2, 0, 0b1000_0011, 0x12, 0x34],
+        encode_run_4bpp, [0xFF; 4], 8, 4, [1, 0, 0b0100_0111, 0xF0],
+        encode_mixed_4bpp,
+            [0x00, 0x00, 0xF0, 0x00, 0x00, 0x00],
+            12,
+            4,
+            [1, 0, 0b0000_0011, 0b1000_0000, 0xF0, 0b0000_0110],
+        encode_padded_rows_1bpp,
+            [0b1010_0000, 0b0101_0000],
+            4,
+            1,
+            [2, 0, 0b1000_0111, 0b1010_0101],
+        encode_long_zero_run_8bpp,
+            [0x00; 100],
+            100,
+            8,
+            [1, 0, 0b0011_1111, 0b0010_0011],
+    }
+}
@@ -1,4 +1,4 @@
-use crate::mplus::font::{Font, FontWidth};
+use crate::font::{Font, FontWidth};
 
 #[derive(Clone, Copy)]
 pub enum Halfwidth {
@@ -9,7 +9,7 @@ pub enum Halfwidth {
 
 impl Halfwidth {
     pub fn from_font(font: &Font, pixels_per_em: f32) -> Self {
-        let em_per_halfwidth = match *font {
+        let em_per_halfwidth = match *font.primary() {
             Font::MPLUSCode {
                 variable: (.., FontWidth(units)),
                 ..
@@ -0,0 +1,388 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::{env, fs, process};
+
+use sha2::{Digest, Sha256};
+
+use crate::Arguments;
+use crate::bitmap::{Compression, Glyph, GlyphList, Image, ImageList};
+use crate::charmap::CharmapEntry;
+use crate::font::Font;
+
+const CACHE_DIR: &str = "MPLUSFONTS_CACHE_DIR";
+const CACHE_VERSION: &str = concat!("mplusfonts ", env!("MPLUSFONTS_CODEGEN_DIGEST"));
+
+static CACHE: LazyLock<Mutex<HashMap<String, Vec<u8>>>> = LazyLock::new(Default::default);
+
+pub fn render_cached(
+    args: &Arguments,
+    layer: usize,
+    render: impl FnOnce() -> BTreeMap<String, CharmapEntry>,
+) -> BTreeMap<String, CharmapEntry> {
+    let key = cache_key(args, layer);
+    let dir = env::var_os(CACHE_DIR).map(PathBuf::from);
+    let bytes = CACHE
+        .lock()
+        .expect("expected no-poison lock on cache")
+        .get(&key)
+        .cloned();
+    let bytes = bytes.or_else(|| dir.as_deref().and_then(|dir| load(dir, &key)));
+    if let Some(entries) = bytes.as_deref().and_then(decode) {
+        CACHE
+            .lock()
+            .expect("expected no-poison lock on cache")
+            .entry(key)
+            .or_insert_with(|| bytes.unwrap_or_default());
+
+        return entries;
+    }
+
+    let entries = render();
+    let bytes = encode(&entries);
+    if let Some(dir) = dir {
+        store(&dir, &key, &bytes);
+    }
+
+    CACHE
+        .lock()
+        .expect("expected no-poison lock on cache")
+        .insert(key, bytes);
+
+    entries
+}
+
+fn cache_key(args: &Arguments, layer: usize) -> String {
+    let Arguments {
+        font,
+        weight,
+        size,
+        hint,
+        positions,
+        bit_depth,
+        charmap: _,
+        compress: _,
+        subpixel,
+        outline,
+        oblique,
+        vertical,
+        features,
+        sources,
+    } = args;
+
+    let is_code = matches!(font.primary(), Font::MPLUSCode { .. });
+    let mut strings = Vec::new();
+    for source_strings in sources.iter().flat_map(|source| source.strings(is_code)) {
+        let source_strings = source_strings.iter().filter(|string| !string.is_empty());
+        strings.extend(source_strings.cloned());
+    }
+
+    let font = font.cache_key();
+    let size = size.to_bits();
+    let oblique = oblique.to_bits();
+    let features: Vec<_> = features.iter().map(ToString::to_string).collect();
+    let features = features.join(" ");
+
+    format!(
+        "{CACHE_VERSION}\n{font}, {weight}, {size:08x}, {hint}, {positions}, {bit_depth}, {subpixel:?}, {outline}, {oblique:08x}, {vertical}, [{features}], {layer}, {strings:?}"
+    )
+}
+
+/// Returns the SHA-256 digest of the specified bytes as a hex string.
+pub fn digest(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn cache_path(dir: &Path, key: &str) -> PathBuf {
+    dir.join(format!("{}.bin", digest(key.as_bytes())))
+}
+
+fn load(dir: &Path, key: &str) -> Option<Vec<u8>> {
+    let bytes = fs::read(cache_path(dir, key)).ok()?;
+    let mut reader = Reader(&bytes);
+    if reader.str()? != key {
+        return None;
+    }
+
+    Some(reader.0.to_vec())
+}
+
+fn store(dir: &Path, key: &str, bytes: &[u8]) {
+    let mut writer = Writer(Vec::with_capacity(key.len() + bytes.len() + 4));
+    writer.str(key);
+    writer.0.extend(bytes);
+
+    let path = cache_path(dir, key);
+    let temp_path = path.with_extension(format!("{}.tmp", process::id()));
+    let result = fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&temp_path, writer.0))
+        .and_then(|_| fs::rename(&temp_path, &path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+}
+
+fn encode(entries: &BTreeMap<String, CharmapEntry>) -> Vec<u8> {
+    let mut writer = Writer(Vec::new());
+    writer.len(entries.len());
+    for entry in entries.values() {
+        let CharmapEntry {
+            key,
+            advance_chars,
+            advance_width_to,
+            advance_width,
+            advance_height,
+            glyphs: GlyphList(glyphs),
+        } = entry;
+
+        writer.str(key);
+        writer.len(*advance_chars);
+        writer.len(advance_width_to.len());
+        for (key, advance_width) in advance_width_to {
+            writer.str(key);
+            writer.f32(*advance_width);
+        }
+
+        writer.f32(*advance_width);
+        writer.f32(*advance_height);
+        writer.len(glyphs.len());
+        for glyph in glyphs {
+            let Glyph {
+                x_offset,
+                y_offset,
+                positions,
+                bit_depth,
+                id,
+                advance_width,
+                advance_height,
+                images: ImageList(images),
+                outlines: ImageList(outlines),
+            } = glyph;
+
+            writer.f32(*x_offset);
+            writer.f32(*y_offset);
+            writer.0.extend([*positions, *bit_depth]);
+            writer.0.extend(id.to_le_bytes());
+            writer.f32(*advance_width);
+            writer.f32(*advance_height);
+            for images in [images, outlines] {
+                writer.len(images.len());
+                for image in images {
+                    let Image {
+                        left,
+                        top,
+                        width,
+                        data,
+                        compression: _,
+                        static_index: _,
+                    } = image;
+
+                    writer.0.extend(left.to_le_bytes());
+                    writer.0.extend(top.to_le_bytes());
+                    writer.0.extend(width.to_le_bytes());
+                    writer.len(data.len());
+                    writer.0.extend(data);
+                }
+            }
+        }
+    }
+
+    writer.0
+}
+
+fn decode(bytes: &[u8]) -> Option<BTreeMap<String, CharmapEntry>> {
+    let mut reader = Reader(bytes);
+    let mut entries = BTreeMap::new();
+    for _ in 0..reader.len()? {
+        let key = reader.str()?.to_owned();
+        let advance_chars = reader.len()?;
+        let mut advance_width_to = BTreeMap::new();
+        for _ in 0..reader.len()? {
+            let key = reader.str()?.to_owned();
+            advance_width_to.insert(key, reader.f32()?);
+        }
+
+        let advance_width = reader.f32()?;
+        let advance_height = reader.f32()?;
+        let mut glyphs = Vec::new();
+        for _ in 0..reader.len()? {
+            let x_offset = reader.f32()?;
+            let y_offset = reader.f32()?;
+            let [positions, bit_depth] = reader.array()?;
+            let id = u16::from_le_bytes(reader.array()?);
+            let advance_width = reader.f32()?;
+            let advance_height = reader.f32()?;
+            let images = reader.images()?;
+            let outlines = reader.images()?;
+            glyphs.push(Glyph {
+                x_offset,
+                y_offset,
+                positions,
+                bit_depth,
+                id,
+                advance_width,
+                advance_height,
+                images,
+                outlines,
+            });
+        }
+
+        let entry = CharmapEntry {
+            key: key.clone(),
+            advance_chars,
+            advance_width_to,
+            advance_width,
+            advance_height,
+            glyphs: GlyphList(glyphs),
+        };
+        entries.insert(key, entry);
+    }
+
+    reader.0.is_empty().then_some(entries)
+}
+
+struct Writer(Vec<u8>);
+
+struct Reader<'a>(&'a [u8]);
+
+impl Writer {
+    fn len(&mut self, len: usize) {
+        let len = u32::try_from(len).expect("expected length to fit in `u32`");
+        self.0.extend(len.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.0.extend(value.to_bits().to_le_bytes());
+    }
+
+    fn str(&mut self, string: &str) {
+        self.len(string.len());
+        self.0.extend(string.as_bytes());
+    }
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, length: usize) -> Option<&'a [u8]> {
+        let (bytes, rest) = self.0.split_at_checked(length)?;
+        self.0 = rest;
+
+        Some(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.bytes(N)?.try_into().ok()
+    }
+
+    fn len(&mut self) -> Option<usize> {
+        let len = u32::from_le_bytes(self.array()?);
+
+        Some(len as usize)
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        let bits = u32::from_le_bytes(self.array()?);
+
+        Some(f32::from_bits(bits))
+    }
+
+    fn str(&mut self) -> Option<&'a str> {
+        let length = self.len()?;
+
+        std::str::from_utf8(self.bytes(length)?).ok()
+    }
+
+    fn images(&mut self) -> Option<ImageList> {
+        let mut images = Vec::new();
+        for _ in 0..self.len()? {
+            let left = i32::from_le_bytes(self.array()?);
+            let top = i32::from_le_bytes(self.array()?);
+            let width = u32::from_le_bytes(self.array()?);
+            let length = self.len()?;
+            let data = self.bytes(length)?.to_vec();
+            images.push(Image {
+                left,
+                top,
+                width,
+                data,
+                compression: Compression::None,
+                static_index: None,
+            });
+        }
+
+        Some(ImageList(images))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitmap::from_outline;
+
+    macro_rules! test_render_cached {
+        (
+            $(
+                $fn_ident:ident, $input:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let args: Arguments = syn::parse_str($input).expect("expected arguments");
+                    let dir = tempfile::tempdir().expect("expected temporary directory");
+                    for layer in 0..args.font.layers() {
+                        let expected = encode(&from_outline::render(&args, layer));
+                        let result = render_cached(&args, layer, || from_outline::render(&args, layer));
+                        assert_eq!(encode(&result), expected);
+
+                        let result = render_cached(&args, layer, || panic!("expected cache hit"));
+                        assert_eq!(encode(&result), expected);
+
+                        let decoded = decode(&expected).expect("expected entries");
+                        assert_eq!(encode(&decoded), expected);
+
+                        let key = cache_key(&args, layer);
+                        store(dir.path(), &key, &expected);
+                        assert_eq!(load(dir.path(), &key), Some(expected));
+                        assert_eq!(load(dir.path(), &format!("{key} ")), None);
+                    }
+                }
+            )*
+        }
+    }
+
+    test_render_cached! {
+        render_cached_1_400_12_false_2_4,
+            "1, 400, 12, false, 2, 4, kern('a'..='z', [\"ffi\"]), [\"東京\", \"g\u{308}\"]",
+        render_cached_code_100_500_16_true_1_8,
+            "code(100), 500, 16, true, 1, 8, '0'..='9', 'ぁ'..='ゖ'",
+        render_cached_fallback_file_2_400_20_false_1_4,
+            "fallback(file(\"fonts/MPLUS1[wght].ttf\"), 2), 400, 20, false, 1, 4, [\"Aあ\"]",
+        render_cached_1_400_12_false_2_4_outline_1,
+            "font = 1, size = 12, positions = 2, outline = 1, sources = ['a'..='z']",
+        render_cached_1_400_12_false_1_4_oblique_12,
+            "font = 1, size = 12, oblique = 12, sources = ['a'..='z']",
+        render_cached_2_400_12_false_1_4_vertical,
+            "font = 2, size = 12, vertical = true, sources = [[\"「縦書き」、ー\"]]",
+        render_cached_1_400_12_false_1_4_features,
+            "font = 1, size = 12, features = [\"-kern\", \"fwid\"], sources = [\"01AV\"]",
+    }
+
+    #[test]
+    fn cache_key_differs_by_sources() {
+        let args: Arguments = syn::parse_str("1, 400, 12, false, 1, 4, [\"a\"]").unwrap();
+        let other: Arguments = syn::parse_str("1, 400, 12, false, 1, 4, [\"b\"]").unwrap();
+        assert_ne!(cache_key(&args, 0), cache_key(&other, 0));
+    }
+
+    #[test]
+    fn cache_key_contains_strings() {
+        let args: Arguments = syn::parse_str("1, 400, 12, false, 1, 4, [\"a\", \"b, c\"]").unwrap();
+        let other: Arguments =
+            syn::parse_str("1, 400, 12, false, 1, 4, [\"a\", \"b\", \"c\"]").unwrap();
+        assert!(cache_key(&args, 0).ends_with(", [\"a\", \"b, c\"]"));
+        assert_ne!(cache_key(&args, 0), cache_key(&other, 0));
+    }
+}
@@ -0,0 +1,109 @@
+mod entry;
+
+use std::collections::BTreeMap;
+
+use swash::text::Codepoint;
+use syn::parse::{Parse, ParseStream};
+
+pub use entry::CharmapEntry;
+
+pub enum Charmap {
+    Tree(Option<CharmapEntry>, BTreeMap<char, Charmap>),
+    Table(BTreeMap<String, CharmapEntry>),
+}
+
+#[derive(Clone, Copy)]
+pub enum CharmapKind {
+    Tree,
+    Table,
+}
+
+impl Charmap {
+    pub fn entries(&self) -> Vec<&CharmapEntry> {
+        match self {
+            Self::Tree(payload, charmap) => {
+                let entries = charmap.values().flat_map(Self::entries);
+
+                payload.iter().chain(entries).collect()
+            }
+            Self::Table(entries) => entries.values().collect(),
+        }
+    }
+
+    pub fn compositions(&self) -> BTreeMap<String, String> {
+        compositions(self.entries())
+    }
+
+    pub fn new(kind: CharmapKind, entries: BTreeMap<String, CharmapEntry>) -> Self {
+        match kind {
+            CharmapKind::Tree => Self::from_iter(entries),
+            CharmapKind::Table => Self::Table(entries),
+        }
+    }
+}
+
+pub fn compositions<'a>(
+    entries: impl IntoIterator<Item = &'a CharmapEntry>,
+) -> BTreeMap<String, String> {
+    let mut compositions = BTreeMap::new();
+    for CharmapEntry { key, .. } in entries {
+        let mut chars = key.chars();
+        let (Some(char), None) = (chars.next(), chars.next()) else {
+            continue;
+        };
+
+        let decomposed: String = char.decompose().collect();
+        let mut chars = decomposed.chars();
+        let composed = chars
+            .next()
+            .and_then(|first| chars.try_fold(first, char::compose));
+        if decomposed.chars().count() > 1 && composed == Some(char) {
+            compositions.insert(decomposed, key.clone());
+        }
+    }
+
+    compositions
+}
+
+impl FromIterator<(String, CharmapEntry)> for Charmap {
+    fn from_iter<T: IntoIterator<Item = (String, CharmapEntry)>>(entries: T) -> Self {
+        let mut payload = None;
+        let mut groups = BTreeMap::new();
+        for (mut key, entry) in entries {
+            let duplicate = if key.is_empty() {
+                payload.replace(entry)
+            } else {
+                let first = key.remove(0);
+                groups
+                    .entry(first)
+                    .or_insert_with(BTreeMap::new)
+                    .insert(key, entry)
+            };
+            let duplicate = duplicate.map(|entry| entry.key);
+            debug_assert_eq!(None, duplicate, "expected field `0` to have a unique value");
+        }
+
+        let mut charmap = BTreeMap::new();
+        for (key, group) in groups {
+            let charmap = charmap.insert(key, Self::from_iter(group));
+            debug_assert!(charmap.is_none(), "expected unique key: `{key:?}`");
+        }
+
+        Charmap::Tree(payload, charmap)
+    }
+}
+
+impl Parse for CharmapKind {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "tree" => Ok(Self::Tree),
+            "table" => Ok(Self::Table),
+            name => {
+                let message = format!("expected identifier `tree` or `table`, found `{name}`");
+
+                Err(syn::Error::new(ident.span(), message))
+            }
+        }
+    }
+}
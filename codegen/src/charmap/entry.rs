@@ -3,16 +3,37 @@ use std::collections::BTreeMap;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
-use crate::mplus::bitmap::GlyphList;
+use crate::bitmap::{GlyphList, Image};
 
 pub struct CharmapEntry {
     pub key: String,
     pub advance_chars: usize,
     pub advance_width_to: BTreeMap<String, f32>,
     pub advance_width: f32,
+    pub advance_height: f32,
     pub glyphs: GlyphList,
 }
 
+impl CharmapEntry {
+    pub fn images(&self) -> impl Iterator<Item = &Image> {
+        let Self { glyphs, .. } = self;
+
+        glyphs
+            .0
+            .iter()
+            .flat_map(|glyph| glyph.images.0.iter().chain(&glyph.outlines.0))
+    }
+
+    pub fn images_mut(&mut self) -> impl Iterator<Item = &mut Image> {
+        let Self { glyphs, .. } = self;
+
+        glyphs
+            .0
+            .iter_mut()
+            .flat_map(|glyph| glyph.images.0.iter_mut().chain(&mut glyph.outlines.0))
+    }
+}
+
 impl ToTokens for CharmapEntry {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let CharmapEntry {
@@ -20,6 +41,7 @@ impl ToTokens for CharmapEntry {
             advance_chars,
             advance_width_to: map,
             advance_width: default,
+            advance_height,
             glyphs,
         } = self;
 
@@ -35,6 +57,7 @@ impl ToTokens for CharmapEntry {
                 key: #key,
                 advance_chars: #advance_chars,
                 advance_width_to: #advance_width_to,
+                advance_height: #advance_height,
                 glyph: #glyphs,
             }
         };
@@ -0,0 +1,439 @@
+mod feature;
+mod metrics;
+mod size;
+mod weight;
+mod width;
+
+use std::path::PathBuf;
+use std::{env, fs};
+
+use lazy_static_include::lazy_static_include_bytes;
+use proc_macro2::Span;
+use swash::{CacheKey, FontRef, NormalizedCoord, Variation};
+use syn::parse::{Parse, ParseStream};
+
+use super::ExprPathExt;
+use crate::cache;
+
+pub use feature::FontFeature;
+pub use metrics::FontMetrics;
+pub use size::FontSize;
+pub use weight::FontWeight;
+pub use width::FontWidth;
+
+lazy_static_include_bytes! {
+    MPLUS1 => "fonts/MPLUS1[wght].ttf",
+    MPLUS2 => "fonts/MPLUS2[wght].ttf",
+    MPLUSCodeLatin => "fonts/MPLUSCodeLatin[wdth,wght].ttf",
+}
+
+pub enum Font {
+    MPLUS1(u32, CacheKey),
+    MPLUS2(u32, CacheKey),
+    MPLUSCode {
+        variable: (u32, CacheKey, FontWidth<125>),
+        fallback: (u32, CacheKey),
+    },
+    File {
+        name: String,
+        path: PathBuf,
+        data: Vec<u8>,
+        offset: u32,
+        key: CacheKey,
+        width: Option<u16>,
+    },
+    Fallback(Vec<Font>),
+}
+
+impl Font {
+    pub fn primary(&self) -> &Self {
+        match self {
+            Self::Fallback(fonts) => fonts.first().expect("expected font"),
+            font => font,
+        }
+    }
+
+    pub fn layers(&self) -> usize {
+        match self {
+            Self::MPLUSCode { .. } => 2,
+            Self::Fallback(fonts) => fonts.len(),
+            _ => 1,
+        }
+    }
+
+    fn layer(&self, layer: usize) -> (&Self, usize) {
+        match self {
+            Self::Fallback(fonts) => (&fonts[layer], 0),
+            font => (font, layer),
+        }
+    }
+
+    pub fn as_ref(&self, layer: usize) -> FontRef<'_> {
+        let (data, offset, key) = match self.layer(layer) {
+            (&Self::MPLUS1(offset, key), _) => (MPLUS1.as_ref(), offset, key),
+            (&Self::MPLUS2(offset, key), _) => (MPLUS2.as_ref(), offset, key),
+            (
+                &Self::MPLUSCode {
+                    fallback: (offset, key),
+                    ..
+                },
+                1..,
+            ) => (MPLUS1.as_ref(), offset, key),
+            (
+                &Self::MPLUSCode {
+                    variable: (offset, key, _),
+                    ..
+                },
+                _,
+            ) => (MPLUSCodeLatin.as_ref(), offset, key),
+            (
+                &Self::File {
+                    ref data,
+                    offset,
+                    key,
+                    ..
+                },
+                _,
+            ) => (data.as_slice(), offset, key),
+            (Self::Fallback(_), _) => panic!("expected no nested fallback fonts"),
+        };
+
+        FontRef { data, offset, key }
+    }
+
+    pub fn cache_key(&self) -> String {
+        match self {
+            Self::MPLUS1(..) => String::from("1"),
+            Self::MPLUS2(..) => String::from("2"),
+            Self::MPLUSCode {
+                variable: (.., FontWidth(width)),
+                ..
+            } => format!("code({width})"),
+            Self::File {
+                data,
+                offset,
+                width,
+                ..
+            } => {
+                let digest = cache::digest(data);
+
+                format!(
+                    "file({digest}, {len}, {offset}, {width:?})",
+                    len = data.len()
+                )
+            }
+            Self::Fallback(fonts) => {
+                let fonts: Vec<_> = fonts.iter().map(Self::cache_key).collect();
+
+                format!("fallback({})", fonts.join(", "))
+            }
+        }
+    }
+
+    pub fn is_mpluscode(&self, layer: usize) -> Option<bool> {
+        match self.layer(layer) {
+            (Self::MPLUS1(..) | Self::MPLUS2(..), _) => Some(false),
+            (Self::MPLUSCode { .. }, layer) => Some(layer == 0),
+            _ => None,
+        }
+    }
+
+    pub fn variation(&self, tag: &str, layer: usize) -> Option<Variation<'_>> {
+        let tag = swash::tag_from_str_lossy(tag);
+
+        self.as_ref(layer).variations().find_by_tag(tag)
+    }
+
+    pub fn coords(&self, weight: u16, layer: usize) -> Vec<NormalizedCoord> {
+        let mut coords = Vec::new();
+        if let Some(weight_axis) = self.variation("wght", layer) {
+            coords.push(weight_axis.normalize(weight.into()));
+        }
+
+        let width = match self.layer(layer) {
+            (
+                &Self::MPLUSCode {
+                    variable: (.., FontWidth(units)),
+                    ..
+                },
+                0,
+            ) => Some(units),
+            (&Self::File { width, .. }, _) => width,
+            _ => None,
+        };
+        if let Some(units) = width {
+            let width_axis = self
+                .variation("wdth", layer)
+                .expect("expected font width axis");
+
+            coords.push(width_axis.normalize(units.into()));
+        }
+
+        coords
+    }
+
+    pub fn check_weight(&self, value: u16, span: Span) -> syn::Result<u16> {
+        let name = match self {
+            Self::File { name, .. } => name,
+            Self::Fallback(fonts) => {
+                for font in fonts {
+                    font.check_weight(value, span)?;
+                }
+
+                return Ok(value);
+            }
+            _ => return Ok(value),
+        };
+
+        match self.variation("wght", 0) {
+            Some(weight_axis) => {
+                let min = weight_axis.min_value();
+                let max = weight_axis.max_value();
+                if f32::from(value) < min || f32::from(value) > max {
+                    let message =
+                        format!("expected number between `{min}` and `{max}`, found `{value}`");
+                    return Err(syn::Error::new(span, message));
+                }
+
+                Ok(value)
+            }
+            None => {
+                let weight = self.default_weight();
+                if value != weight {
+                    let message = format!(
+                        "font file `{name}` has no `wght` axis; expected `{weight}`, found `{value}`"
+                    );
+                    return Err(syn::Error::new(span, message));
+                }
+
+                Ok(value)
+            }
+        }
+    }
+
+    pub fn default_weight(&self) -> u16 {
+        match self.variation("wght", 0) {
+            Some(weight_axis) => {
+                let min = weight_axis.min_value();
+                let max = weight_axis.max_value();
+
+                400f32.clamp(min, max) as u16
+            }
+            None => self.as_ref(0).attributes().weight().0,
+        }
+    }
+
+    pub fn paths(&self) -> Vec<&PathBuf> {
+        match self {
+            Self::File { path, .. } => Vec::from([path]),
+            Self::Fallback(fonts) => fonts.iter().flat_map(Self::paths).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Parse for Font {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let font = match input.parse()? {
+            syn::Expr::Lit(expr_lit) => expr_lit.try_into()?,
+            syn::Expr::Call(expr_call) => expr_call.try_into()?,
+            expr => {
+                let message = "expected literal or function call expression";
+                return Err(syn::Error::new_spanned(expr, message));
+            }
+        };
+
+        Ok(font)
+    }
+}
+
+impl TryFrom<syn::ExprLit> for Font {
+    type Error = syn::Error;
+
+    fn try_from(expr_lit: syn::ExprLit) -> Result<Self, Self::Error> {
+        let syn::Lit::Int(lit_int) = expr_lit.lit else {
+            let message = "expected integer literal";
+            return Err(syn::Error::new_spanned(expr_lit.lit, message));
+        };
+
+        let font = match lit_int.base10_digits() {
+            "1" => {
+                let font_ref = FontRef::from_index(&MPLUS1, 0).expect("expected font");
+                Self::MPLUS1(font_ref.offset, font_ref.key)
+            }
+            "2" => {
+                let font_ref = FontRef::from_index(&MPLUS2, 0).expect("expected font");
+                Self::MPLUS2(font_ref.offset, font_ref.key)
+            }
+            digits => {
+                let message = format!("expected number `1` or `2`, found `{digits}`");
+                return Err(syn::Error::new(lit_int.span(), message));
+            }
+        };
+
+        Ok(font)
+    }
+}
+
+impl TryFrom<syn::ExprCall> for Font {
+    type Error = syn::Error;
+
+    fn try_from(expr_call: syn::ExprCall) -> Result<Self, Self::Error> {
+        let syn::Expr::Path(expr_path) = *expr_call.func else {
+            let message = "expected identifier";
+            return Err(syn::Error::new_spanned(expr_call.func, message));
+        };
+
+        let ident = expr_path.try_into_ident()?;
+        let name = ident.to_string();
+        if name == "file" {
+            return file_from_args(expr_call.args, expr_call.paren_token);
+        }
+
+        if name == "fallback" {
+            return fallback_from_args(expr_call.args, expr_call.paren_token);
+        }
+
+        if name != "code" {
+            let message =
+                format!("expected identifier `code`, `file` or `fallback`, found `{name}`");
+            return Err(syn::Error::new(ident.span(), message));
+        }
+
+        let mut exprs = expr_call.args.into_iter();
+        let Some(first) = exprs.next() else {
+            let message = "expected 1 argument, found 0";
+            return Err(syn::Error::new(expr_call.paren_token.span.join(), message));
+        };
+        let width = first.try_into()?;
+
+        if let Some(second) = exprs.next() {
+            let message = "remove the extra argument";
+            return Err(syn::Error::new_spanned(second, message));
+        }
+
+        let variable_font_ref = FontRef::from_index(&MPLUSCodeLatin, 0).expect("expected font");
+        let fallback_font_ref = FontRef::from_index(&MPLUS1, 0).expect("expected font");
+        let font = Self::MPLUSCode {
+            variable: (variable_font_ref.offset, variable_font_ref.key, width),
+            fallback: (fallback_font_ref.offset, fallback_font_ref.key),
+        };
+
+        Ok(font)
+    }
+}
+
+fn file_from_args(
+    args: impl IntoIterator<Item = syn::Expr>,
+    paren_token: syn::token::Paren,
+) -> syn::Result<Font> {
+    let mut exprs = args.into_iter();
+    let Some(first) = exprs.next() else {
+        let message = "expected 1 or 2 arguments, found 0";
+        return Err(syn::Error::new(paren_token.span.join(), message));
+    };
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit_str),
+        ..
+    }) = first
+    else {
+        let message = "expected string literal";
+        return Err(syn::Error::new_spanned(first, message));
+    };
+
+    let name = lit_str.value();
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let path = PathBuf::from(manifest_dir).join(&name);
+    let data = match fs::read(&path) {
+        Ok(data) => data,
+        Err(e) => {
+            let message = format!("failed to read font file `{name}`: {e}");
+            return Err(syn::Error::new(lit_str.span(), message));
+        }
+    };
+    let Some(font_ref) = FontRef::from_index(&data, 0) else {
+        let message = format!("expected TrueType or OpenType font file, found `{name}`");
+        return Err(syn::Error::new(lit_str.span(), message));
+    };
+
+    let width = match exprs.next() {
+        Some(syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit_int),
+            ..
+        })) => {
+            let value: u16 = lit_int.base10_parse()?;
+            let tag = swash::tag_from_str_lossy("wdth");
+            let Some(width_axis) = font_ref.variations().find_by_tag(tag) else {
+                let message = format!("font file `{name}` has no `wdth` axis");
+                return Err(syn::Error::new(lit_int.span(), message));
+            };
+
+            let min = width_axis.min_value();
+            let max = width_axis.max_value();
+            if f32::from(value) < min || f32::from(value) > max {
+                let message =
+                    format!("expected number between `{min}` and `{max}`, found `{value}`");
+                return Err(syn::Error::new(lit_int.span(), message));
+            }
+
+            Some(value)
+        }
+        Some(second) => {
+            let message = "expected integer literal";
+            return Err(syn::Error::new_spanned(second, message));
+        }
+        None => None,
+    };
+
+    if let Some(third) = exprs.next() {
+        let message = "remove the extra argument";
+        return Err(syn::Error::new_spanned(third, message));
+    }
+
+    let (offset, key) = (font_ref.offset, font_ref.key);
+    let font = Font::File {
+        name,
+        path,
+        data,
+        offset,
+        key,
+        width,
+    };
+
+    Ok(font)
+}
+
+fn fallback_from_args(
+    args: impl IntoIterator<Item = syn::Expr>,
+    paren_token: syn::token::Paren,
+) -> syn::Result<Font> {
+    let mut fonts = Vec::new();
+    for expr in args {
+        let font = match expr {
+            syn::Expr::Lit(expr_lit) => expr_lit.try_into()?,
+            syn::Expr::Call(expr_call) => {
+                let font: Font = expr_call.clone().try_into()?;
+                if let Font::Fallback(_) = font {
+                    let message = "remove the nested `fallback`";
+                    return Err(syn::Error::new_spanned(expr_call, message));
+                }
+
+                font
+            }
+            expr => {
+                let message = "expected literal or function call expression";
+                return Err(syn::Error::new_spanned(expr, message));
+            }
+        };
+
+        fonts.push(font);
+    }
+
+    if fonts.len() < 2 {
+        let length = fonts.len();
+        let message = format!("expected at least 2 arguments, found {length}");
+        return Err(syn::Error::new(paren_token.span.join(), message));
+    }
+
+    Ok(Font::Fallback(fonts))
+}
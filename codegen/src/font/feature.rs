@@ -0,0 +1,54 @@
+use std::fmt;
+
+use swash::Setting;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FontFeature {
+    pub tag: [u8; 4],
+    pub value: u16,
+}
+
+impl FontFeature {
+    pub const fn new(tag: &[u8; 4], value: u16) -> Self {
+        Self { tag: *tag, value }
+    }
+
+    pub fn setting(&self) -> Setting<u16> {
+        Setting::from(&(self.tag, self.value))
+    }
+}
+
+impl TryFrom<syn::LitStr> for FontFeature {
+    type Error = syn::Error;
+
+    fn try_from(lit_str: syn::LitStr) -> Result<Self, Self::Error> {
+        let string = lit_str.value();
+        let (name, value) = match (string.strip_prefix('-'), string.split_once('=')) {
+            (Some(name), _) => (name, "0"),
+            (None, Some((name, value))) => (name, value),
+            (None, None) => (string.as_str(), "1"),
+        };
+
+        let tag = <[u8; 4]>::try_from(name.as_bytes()).ok();
+        let Some(tag) = tag.filter(|tag| tag.iter().all(u8::is_ascii_alphanumeric)) else {
+            let message = format!("expected feature tag of 4 letters or digits, found `{name}`");
+            return Err(syn::Error::new(lit_str.span(), message));
+        };
+
+        let Ok(value) = value.parse() else {
+            let message = format!("expected number between `0` and `65535`, found `{value}`");
+            return Err(syn::Error::new(lit_str.span(), message));
+        };
+
+        Ok(Self { tag, value })
+    }
+}
+
+impl fmt::Display for FontFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { tag, value } = self;
+        let tag = String::from_utf8_lossy(tag);
+
+        write!(f, "{tag}={value}")
+    }
+}
@@ -0,0 +1,60 @@
+use super::Font;
+
+pub struct FontMetrics {
+    pub top: f32,
+    pub ascender: f32,
+    pub cap_height: f32,
+    pub x_height: f32,
+    pub descender: f32,
+    pub bottom: f32,
+    pub underline_offset: f32,
+    pub strikethrough_offset: f32,
+    pub stroke_size: f32,
+}
+
+impl FontMetrics {
+    pub fn from_font(font: &Font, weight: u16) -> Self {
+        match font.primary() {
+            Font::MPLUS1(..) | Font::MPLUS2(..) => Self {
+                top: 1.16,
+                ascender: 0.88,
+                cap_height: 0.73,
+                x_height: 0.52,
+                descender: -0.12,
+                bottom: -0.288,
+                underline_offset: -0.1,
+                strikethrough_offset: 0.312,
+                stroke_size: 0.05,
+            },
+            Font::MPLUSCode { .. } => Self {
+                top: 1.235,
+                ascender: 1.0,
+                cap_height: 0.73,
+                x_height: 0.52,
+                descender: -0.235,
+                bottom: -0.27,
+                underline_offset: -0.1,
+                strikethrough_offset: 0.312,
+                stroke_size: 0.05,
+            },
+            font => {
+                let coords = font.coords(weight, 0);
+                let metrics = font.as_ref(0).metrics(&coords);
+                let units_per_em = f32::from(metrics.units_per_em);
+                let half_leading = metrics.leading / 2.0;
+
+                Self {
+                    top: (metrics.ascent + half_leading) / units_per_em,
+                    ascender: metrics.ascent / units_per_em,
+                    cap_height: metrics.cap_height / units_per_em,
+                    x_height: metrics.x_height / units_per_em,
+                    descender: -metrics.descent / units_per_em,
+                    bottom: -(metrics.descent + half_leading) / units_per_em,
+                    underline_offset: metrics.underline_offset / units_per_em,
+                    strikethrough_offset: metrics.strikeout_offset / units_per_em,
+                    stroke_size: metrics.stroke_size / units_per_em,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! The bitmap font generator behind the `mplus!` and `mplus_bytes!` macros, shared by
+//! [`mplusfonts-macros`](https://docs.rs/mplusfonts-macros) and
+//! [`mplusfonts-build`](https://docs.rs/mplusfonts-build).
+//!
+//! This crate is an implementation detail of those crates and has no stable API.
+
+mod args;
+mod bitmap;
+mod cache;
+mod charmap;
+mod expr;
+mod font;
+mod source;
+
+use std::collections::BTreeMap;
+
+use bitmap::{Compression, Subpixel};
+use charmap::{Charmap, CharmapEntry};
+use expr::ExprPathExt;
+use font::{Font, FontMetrics};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+
+pub use args::Arguments;
+pub use bitmap::{BitmapFont, BitmapFontBlob};
+pub use source::CharSource;
+
+pub fn mplus_impl(mut args: Arguments) -> TokenStream {
+    match bitmap_font(&mut args) {
+        Ok(font) => include_paths(&args.font, font),
+        Err(e) => e.into_compile_error(),
+    }
+}
+
+pub fn mplus_bytes_impl(mut args: Arguments) -> TokenStream {
+    match bitmap_font_blob(&mut args) {
+        Ok(blob) => include_paths(&args.font, blob),
+        Err(e) => e.into_compile_error(),
+    }
+}
+
+pub fn bitmap_font(args: &mut Arguments) -> syn::Result<BitmapFont> {
+    if args.outline > 0 && args.subpixel != Subpixel::None {
+        let message = "expected grayscale glyph images for outlines, remove `subpixel`";
+        return Err(syn::Error::new(Span::call_site(), message));
+    }
+
+    if args.vertical && args.subpixel != Subpixel::None {
+        let message = "expected grayscale glyph images for vertical writing, remove `subpixel`";
+        return Err(syn::Error::new(Span::call_site(), message));
+    }
+
+    let (mut entries, mut notdef) = render(args);
+    for entry in entries.values_mut().chain([&mut notdef]) {
+        for image in entry.images_mut() {
+            image.compress(args.compress, args.bit_depth);
+        }
+    }
+
+    let entries_mut = entries.values_mut().chain([&mut notdef]);
+    bitmap::index_statics(entries_mut.flat_map(CharmapEntry::images_mut));
+
+    let charmap = Charmap::new(args.charmap, entries);
+    let positions = args.positions;
+    let bit_depth = args.bit_depth;
+    let subpixel = args.subpixel;
+    let size = args.size;
+    let metrics = FontMetrics::from_font(&args.font, args.weight);
+
+    Ok(BitmapFont {
+        charmap,
+        notdef,
+        positions,
+        bit_depth,
+        subpixel,
+        size,
+        metrics,
+    })
+}
+
+pub fn bitmap_font_blob(args: &mut Arguments) -> syn::Result<BitmapFontBlob> {
+    if args.compress != Compression::None {
+        let message = "expected uncompressed glyph images in binary format, remove `compress`";
+        return Err(syn::Error::new(Span::call_site(), message));
+    }
+
+    if args.subpixel != Subpixel::None {
+        let message = "expected grayscale glyph images in binary format, remove `subpixel`";
+        return Err(syn::Error::new(Span::call_site(), message));
+    }
+
+    if args.outline > 0 {
+        let message = "expected glyph images without outlines in binary format, remove `outline`";
+        return Err(syn::Error::new(Span::call_site(), message));
+    }
+
+    if args.vertical {
+        let message =
+            "expected glyph images for horizontal writing in binary format, remove `vertical`";
+        return Err(syn::Error::new(Span::call_site(), message));
+    }
+
+    let (entries, notdef) = render(args);
+    let positions = args.positions;
+    let bit_depth = args.bit_depth;
+    let size = args.size;
+    let metrics = FontMetrics::from_font(&args.font, args.weight);
+
+    Ok(BitmapFontBlob {
+        entries,
+        notdef,
+        positions,
+        bit_depth,
+        size,
+        metrics,
+    })
+}
+
+fn render(args: &mut Arguments) -> (BTreeMap<String, CharmapEntry>, CharmapEntry) {
+    let notdef = CharSource::Strings(vec![String::from("\u{FFFD}")]);
+    args.sources.push(notdef);
+
+    let mut entries = BTreeMap::new();
+    for layer in (0..args.font.layers()).rev() {
+        let render = || bitmap::from_outline::render(args, layer);
+        entries.extend(cache::render_cached(args, layer, render));
+    }
+
+    let notdef = entries.remove("\u{FFFD}").expect("expected `\u{FFFD}`");
+
+    (entries, notdef)
+}
+
+fn include_paths(font: &Font, tokens: impl ToTokens) -> TokenStream {
+    let paths = font.paths();
+    if paths.is_empty() {
+        return tokens.into_token_stream();
+    }
+
+    let paths = paths.into_iter().map(|path| path.to_string_lossy());
+    quote!({
+        #(const _: &[u8] = include_bytes!(#paths);)*
+
+        #tokens
+    })
+}
@@ -19,6 +19,7 @@ pub fn main() -> Result<(), Infallible> {
     let character_style = BitmapFontStyleBuilder::new()
         .text_color(Rgb565::new(30, 60, 30))
         .font(&bitmap_font)
+        .tabular_figures()
         .build();
 
     let text_style = TextStyleBuilder::new()
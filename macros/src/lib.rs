@@ -2,9 +2,9 @@
 //! Coji Morishita; it is a dependency of [`mplusfonts`](../mplusfonts/index.html), with font
 //! rasterization powered by [`swash`].
 
-mod mplus;
 mod strings;
 
+use mplusfonts_codegen as mplus;
 use proc_macro::TokenStream;
 use syn::{meta, parse_macro_input};
 
@@ -84,7 +84,19 @@ pub fn strings(args: TokenStream, input: TokenStream) -> TokenStream {
 ///   for glyphs that are not parametrized by width.
 ///   * `code(width)` - Font width. Ranges from `100` to `125`. Only available as a parameter to
 ///     `code`.
-/// * `weight` - Font weight. Ranges from `100` to `900`. Capped at `700` for `code`.
+///   * `file(path)` or `file(path, width)` - TrueType or OpenType font file, resolved relative to
+///     the directory that contains the manifest of the crate being compiled. The glyphs are shaped
+///     and scaled the same way as for the **M<sup>+</sup>** fonts, and the font metrics are read
+///     from the file. If specified, `width` sets the position on the `wdth` axis of the font,
+///     which results in an error if the file does not have such an axis.
+///   * `fallback(font, ...)` - Fallback chain of two or more of the above, in order of priority.
+///     Glyphs that are missing from the first font are taken from the next font that has them.
+///     The font metrics and the font width of `code`, if used for the first font, apply to all
+///     glyphs. When specified as part of a fallback chain, `code` does not fall back to
+///     **M<sup>+</sup> 1**.
+/// * `weight` - Font weight. Ranges from `100` to `900`. Capped at `700` for `code`. For `file`,
+///   it sets the position on the `wght` axis of the font, within the range of the axis. If the
+///   file does not have such an axis, this argument has to match the weight of the font.
 /// * `size` - Font size. Specify either as a value in pixels per _em_-size or, for convenience,
 ///   specify one of helpers listed here, all of which take a `px` parameter, performing a
 ///   conversion to pixels per _em_-size. In both cases, any `.0` can be omitted.
@@ -120,6 +132,76 @@ pub fn strings(args: TokenStream, input: TokenStream) -> TokenStream {
 ///
 /// The optional `sources` argument makes this a variadic-function-like procedural macro.
 ///
+/// # Keyword arguments
+///
+/// All of the above can also be specified as `name = value` pairs, in any order. The arguments
+/// `font` and `size` are required, while the others default to the values listed here.
+///
+/// | Argument    | Default Value  |
+/// |-------------|----------------|
+/// | `weight`    | `400`[^weight] |
+/// | `hint`      | `false`        |
+/// | `positions` | `1`            |
+/// | `bit_depth` | `4`            |
+/// | `charmap`   | `table`        |
+/// | `compress`  | `none`         |
+/// | `subpixel`  | `none`         |
+/// | `outline`   | `0`            |
+/// | `oblique`   | `0`            |
+/// | `vertical`  | `false`        |
+/// | `features`  | `[]`           |
+/// | `sources`   | `[]`           |
+///
+/// [^weight]: For a `file` that does not have a `wght` axis, the weight of the font.
+///
+/// The `charmap` argument is only available as a keyword argument. With `table`, the charmap
+/// entries are stored in a table that is sorted by key and searched using binary search; with
+/// `tree`, each character of a key is matched by a closure with a `match` expression, as in
+/// previous versions, which is faster to look up but takes up more flash memory.
+///
+/// The `compress` argument is also only available as a keyword argument. With `rle`, each glyph
+/// image is run-length encoded and decoded while it is being drawn, which reduces the size of
+/// fonts that have large glyphs with long runs of blank or solid pixels, at the cost of drawing
+/// time. It cannot be used with [`mplus_bytes!`].
+///
+/// The `subpixel` argument is also only available as a keyword argument. With `rgb` or `bgr`, glyph
+/// outlines are rendered at three times the horizontal resolution and filtered to reduce color
+/// fringes, storing three gray values per pixel, one for each sub-pixel of a color display that
+/// has its sub-pixels in the specified order. Text is then drawn by blending each color channel
+/// between the background and the text color, which makes small text appear sharper. It cannot be
+/// used with [`mplus_bytes!`].
+///
+/// The `outline` argument is also only available as a keyword argument. With a value from `1` to
+/// `8`, each glyph outline is also rendered with a stroke of that many pixels around it, storing a
+/// second set of glyph images for the outline layer. Text is then drawn with the outline layer
+/// under the glyphs when the style has an outline color, which keeps labels legible over images and
+/// maps. It cannot be used with `subpixel` nor with [`mplus_bytes!`].
+///
+/// The `oblique` argument is also only available as a keyword argument. With a value in degrees
+/// from `-30` to `30`, glyph outlines are slanted to the right, or to the left for negative values,
+/// by shearing them along the baseline, which gives fonts that have no italic styles a synthetic
+/// oblique style. The advance widths are not changed; glyph images that overhang into the next
+/// glyph are blended with it when drawn.
+///
+/// The `vertical` argument is also only available as a keyword argument. With `true`, text is
+/// shaped with the `vert` and `vrt2` OpenType features, which substitute vertical alternates for
+/// punctuation and other characters that are drawn differently in vertical text, and the vertical
+/// advance of each glyph cluster is stored in its charmap entry, for use with
+/// `BitmapFontStyle::draw_string_vertical`. It cannot be used with `subpixel` nor with
+/// [`mplus_bytes!`].
+///
+/// The `features` argument is also only available as a keyword argument. Its value is a slice
+/// literal expression of string literals, each one an OpenType feature tag, such as `"tnum"` for
+/// tabular figures, `"case"` for case-sensitive forms, or `"ss01"` for a stylistic set. A tag is
+/// switched on by default, switched off with a leading `-`, as in `"-kern"`, or set to a value with
+/// a trailing `=` and a number, as in `"salt=2"`. These settings are applied when shaping text with
+/// every font in the chain, except that `liga` stays switched off for fallback fonts.
+///
+/// The value for `sources` is a slice literal expression. If it only contains string literals, it
+/// is treated as a single `slice` source; otherwise, each of its elements is a separate source.
+/// Additional sources can follow the keyword arguments without a name, which is where
+/// [`macro@strings`] appends the string literals that it has collected.
+///
 /// # Aliases
 ///
 /// Built-in constant-like identifiers can be substituted for common weight and width values.
@@ -152,6 +234,26 @@ pub fn strings(args: TokenStream, input: TokenStream) -> TokenStream {
 /// mplus!(2, 575, line_height(20), true, 4, 4, kern(' '..='ȷ', ["ffi", "ffl"]));
 /// mplus!(code(100), SEMI_BOLD, 18, true, 1, 4, '0'..='9', [",.-"]);
 /// mplus!(code(125), 480, 13.5, true, 1, 4, 'A'..='Z', 'ぁ'..='ゖ');
+/// mplus!(fallback(code(100), 2, 1), 500, 16, true, 1, 4, 'A'..='Z', ["漢字"]);
+/// ```
+///
+/// The same bitmap fonts can be created using keyword arguments:
+///
+/// ```
+/// # use mplusfonts_macros::mplus;
+/// #
+/// mplus!(font = 1, weight = 750, size = x_height(5), positions = 2, sources = ["Yes", "No"]);
+/// mplus!(font = 2, weight = BOLD, size = line_height(20), positions = 2, sources = [["東京"]]);
+/// mplus!(
+///     font = 2,
+///     weight = 575,
+///     size = line_height(20),
+///     hint = true,
+///     positions = 4,
+///     bit_depth = 4,
+///     sources = [kern(' '..='ȷ', ["ffi", "ffl"])],
+/// );
+/// mplus!(font = code(125), weight = 480, size = 13.5, hint = true, sources = ['A'..='Z']);
 /// ```
 ///
 /// The amount of flash memory (storage space for `.rodata`) that is going to be used, will be a
@@ -168,8 +270,39 @@ pub fn strings(args: TokenStream, input: TokenStream) -> TokenStream {
 /// mplus!(1, 500, 50, true, 4, 8, .., ["ff", "fi", "ffi", "fl", "ffl"]);
 /// mplus!(1, 500, 50, true, 4, 8, kern(.., ["ffi", "ffl"]));
 /// ```
+///
+/// # Caching
+///
+/// Rendered glyphs are cached in memory, so that expanding the macro again with the same font,
+/// weight, width, size, hint, positions, bit depth, and strings in the same compiler process does
+/// not rasterize them again. To also keep the cache between builds, set the `MPLUSFONTS_CACHE_DIR`
+/// environment variable to a directory, where each cache entry is going to be stored as a file.
+/// The output is the same with or without the cache; the directory can be deleted at any time.
 #[proc_macro]
 pub fn mplus(input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(input as mplus::Arguments);
     mplus::mplus_impl(args).into()
 }
+
+/// Produces a bitmap font in a serialized binary format, with the same arguments as [`mplus!`].
+///
+/// Instead of a data structure of nested closures, this macro expands to a byte string literal,
+/// which can be written to a file or to a separate flash partition, and read using
+/// `mplusfonts::blob::BitmapFontBlob` without copying. The bytes start with a magic number and a
+/// version number, and they include the bit depth and the number of positions, which must match
+/// the color type and the const generic parameter `N` on the reading side.
+///
+/// # Examples
+///
+/// ```
+/// # use mplusfonts_macros::mplus_bytes;
+/// #
+/// static BYTES: &[u8] = mplus_bytes!(font = 2, size = 16, sources = ["Hello, World!"]);
+///
+/// assert_eq!(b"MPLF", &BYTES[..4]);
+/// ```
+#[proc_macro]
+pub fn mplus_bytes(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as mplus::Arguments);
+    mplus::mplus_bytes_impl(args).into()
+}
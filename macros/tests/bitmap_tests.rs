@@ -1,10 +1,16 @@
 #![cfg(not(feature = "bless-tests"))]
 
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::pixelcolor::{Gray4, Gray8, Rgb888};
 use embedded_graphics::prelude::*;
-use mplusfonts::CharmapEntry;
+use embedded_graphics::text::Text;
+use mplusfonts::blob::BitmapFontBlob;
+use mplusfonts::color::SubpixelOrder;
 use mplusfonts::glyph::Glyph;
 use mplusfonts::image::Colors;
-use mplusfonts_macros::mplus;
+use mplusfonts::style::BitmapFontStyle;
+use mplusfonts::{BitmapFontData, CharmapEntry, CharmapEntryData};
+use mplusfonts_macros::{mplus, mplus_bytes};
 use seq_macro::seq;
 
 macro_rules! test_render_glyphs {
@@ -27,10 +33,12 @@ macro_rules! test_render_glyphs {
                         key,
                         advance_chars,
                         advance_width_to,
+                        advance_height: _,
                         glyph:
                             Glyph {
                                 id,
                                 ref images,
+                                outline: _,
                                 next,
                             },
                     } = *entry;
@@ -120,3 +128,601 @@ test_render_glyphs! {
         seq!(C in 'i'..='j' { [#(concat!(C),)* "ı", "ȷ", "f", "ff", "fi", "ffi"] }),
         include!("bitmap/render_glyphs_1_500_25_false_1_4_kern_dotless_i_j_liga.in"),
 }
+
+macro_rules! test_render_glyphs_from_file {
+    (
+        $(
+            $fn_ident:ident, $bitmap_font:expr, $bitmap_font_from_file:expr, $text_fragments:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let bitmap_font = $bitmap_font;
+                let bitmap_font_from_file = $bitmap_font_from_file;
+                let text_fragments = $text_fragments;
+
+                for slice in text_fragments {
+                    let entry = bitmap_font.charmap.get(slice);
+                    let result = bitmap_font_from_file.charmap.get(slice);
+                    assert_eq!(entry.key, result.key, "\n slice: {slice:?}");
+                    assert_eq!(entry.glyph.id, result.glyph.id, "\n slice: {slice:?}");
+
+                    for to_slice in text_fragments {
+                        assert_eq!(
+                            (entry.advance_width_to)(to_slice),
+                            (result.advance_width_to)(to_slice),
+                            "\n slice: {slice:?}",
+                            slice = entry.key.to_owned() + to_slice
+                        );
+                    }
+
+                    let image = entry.glyph.images.get(0);
+                    let result = result.glyph.images.get(0);
+                    assert_eq!(image.bounding_box(), result.bounding_box(), "\n slice: {slice:?}");
+                    assert!(
+                        image.colors().into_iter().eq(result.colors()),
+                        "\n slice: {slice:?}"
+                    );
+                }
+            }
+        )*
+    }
+}
+
+test_render_glyphs_from_file! {
+    render_glyphs_from_file_1_500_25_false_1_4_kern_space_tilde,
+        mplus!(1, 500, 25, false, 1, 4, kern(' '..='~', [])),
+        mplus!(file("../codegen/fonts/MPLUS1[wght].ttf"), 500, 25, false, 1, 4, kern(' '..='~', [])),
+        seq!(C in ' '..='~' { [#(concat!(C),)*] }),
+
+    render_glyphs_from_file_2_700_20_true_1_4_hiragana_kanji,
+        mplus!(2, 700, 20, true, 1, 4, 'ぁ'..='ゖ', ["東京", "京都"]),
+        mplus!(
+            file("../codegen/fonts/MPLUS2[wght].ttf"), 700, 20, true, 1, 4, 'ぁ'..='ゖ', ["東京", "京都"]
+        ),
+        seq!(C in 'ぁ'..='ゖ' { [#(concat!(C),)* "東", "京", "都"] }),
+}
+
+macro_rules! test_render_glyphs_with_fallback {
+    (
+        $(
+            $fn_ident:ident,
+            $bitmap_font:expr,
+            $bitmap_font_with_fallback:expr,
+            $bitmap_font_for_glyphs:expr,
+            $text_fragments:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let bitmap_font = $bitmap_font;
+                let bitmap_font_with_fallback = $bitmap_font_with_fallback;
+                let bitmap_font_for_glyphs = $bitmap_font_for_glyphs;
+                let text_fragments = $text_fragments;
+
+                for slice in text_fragments {
+                    let entry = bitmap_font.charmap.get(slice);
+                    let result = bitmap_font_with_fallback.charmap.get(slice);
+                    assert_eq!(entry.key, result.key, "\n slice: {slice:?}");
+
+                    for to_slice in text_fragments {
+                        assert_eq!(
+                            (entry.advance_width_to)(to_slice),
+                            (result.advance_width_to)(to_slice),
+                            "\n slice: {slice:?}",
+                            slice = entry.key.to_owned() + to_slice
+                        );
+                    }
+
+                    let entry = bitmap_font_for_glyphs.charmap.get(slice);
+                    assert_eq!(entry.glyph.id, result.glyph.id, "\n slice: {slice:?}");
+
+                    let image = entry.glyph.images.get(0);
+                    let result = result.glyph.images.get(0);
+                    assert_eq!(
+                        image.bounding_box().size,
+                        result.bounding_box().size,
+                        "\n slice: {slice:?}"
+                    );
+                    assert!(
+                        image.colors().into_iter().eq(result.colors()),
+                        "\n slice: {slice:?}"
+                    );
+                }
+            }
+        )*
+    }
+}
+
+test_render_glyphs_with_fallback! {
+    render_glyphs_with_fallback_code_100_2_1_500_25_false_1_4_latin,
+        mplus!(code(100), 500, 25, false, 1, 4, 'A'..='Z', 'a'..='z'),
+        mplus!(fallback(code(100), 2, 1), 500, 25, false, 1, 4, 'A'..='Z', 'a'..='z'),
+        mplus!(code(100), 500, 25, false, 1, 4, 'A'..='Z', 'a'..='z'),
+        seq!(C in 'A'..='Z' { [#(concat!(C),)*] }),
+
+    render_glyphs_with_fallback_code_100_2_1_500_25_false_1_4_hiragana_kanji,
+        mplus!(code(100), 500, 25, false, 1, 4, 'ぁ'..='ゖ', ["東京", "京都"]),
+        mplus!(
+            fallback(code(100), 2, 1), 500, 25, false, 1, 4, 'ぁ'..='ゖ', ["東京", "京都"]
+        ),
+        mplus!(2, 500, 25, false, 1, 4, 'ぁ'..='ゖ', ["東京", "京都"]),
+        seq!(C in 'ぁ'..='ゖ' { [#(concat!(C),)* "東", "京", "都"] }),
+
+    render_glyphs_with_fallback_file_1_400_20_true_1_4_kern_space_tilde,
+        mplus!(file("../codegen/fonts/MPLUSCodeLatin[wdth,wght].ttf"), 400, 20, true, 1, 4, ' '..='~'),
+        mplus!(
+            fallback(file("../codegen/fonts/MPLUSCodeLatin[wdth,wght].ttf"), 1), 400, 20, true, 1, 4, ' '..='~'
+        ),
+        mplus!(file("../codegen/fonts/MPLUSCodeLatin[wdth,wght].ttf"), 400, 20, true, 1, 4, ' '..='~'),
+        seq!(C in ' '..='~' { [#(concat!(C),)*] }),
+}
+
+macro_rules! test_render_glyphs_from_bytes {
+    (
+        $(
+            $fn_ident:ident,
+            $color_type:ty,
+            $positions:literal,
+            $bitmap_font:expr,
+            $bytes:expr,
+            $text_fragments:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let bitmap_font = $bitmap_font;
+                let bitmap_font_blob = BitmapFontBlob::<$color_type, $positions>::new($bytes);
+                let bitmap_font_blob = bitmap_font_blob.expect("expected bitmap font blob");
+                let text_fragments = $text_fragments;
+
+                assert_eq!(bitmap_font.metrics().top, bitmap_font_blob.metrics().top);
+                assert_eq!(bitmap_font.metrics().bottom, bitmap_font_blob.metrics().bottom);
+                assert_eq!(bitmap_font.underline().offset, bitmap_font_blob.underline().offset);
+
+                for slice in text_fragments {
+                    let entry = bitmap_font.get(slice);
+                    let result = bitmap_font_blob.get(slice);
+                    assert_eq!(entry.key(), result.key(), "\n slice: {slice:?}");
+                    assert_eq!(entry.advance_chars(), result.advance_chars(), "\n slice: {slice:?}");
+
+                    for to_slice in text_fragments.into_iter().chain([""]) {
+                        assert_eq!(
+                            entry.advance_width_to(to_slice),
+                            result.advance_width_to(to_slice),
+                            "\n slice: {slice:?}",
+                            slice = entry.key.to_owned() + to_slice
+                        );
+                    }
+
+                    for index in 0..4 {
+                        assert_eq!(entry.glyph(index), result.glyph(index), "\n slice: {slice:?}");
+
+                        for position in 0..$positions {
+                            let image = entry.image(index, position);
+                            let result = result.image(index, position);
+                            assert_eq!(
+                                image.bounding_box(),
+                                result.bounding_box(),
+                                "\n slice: {slice:?}"
+                            );
+                            assert!(
+                                image.colors().into_iter().eq(result.colors()),
+                                "\n slice: {slice:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
+
+test_render_glyphs_from_bytes! {
+    render_glyphs_from_bytes_1_500_25_false_4_4_kern_space_tilde_ligatures,
+        Gray4,
+        4,
+        mplus!(1, 500, 25, false, 4, 4, kern(' '..='~', ["ffi", "ffl"])),
+        mplus_bytes!(1, 500, 25, false, 4, 4, kern(' '..='~', ["ffi", "ffl"])),
+        seq!(C in ' '..='~' { [#(concat!(C),)* "ffi", "ffl", "ff", "\u{FFFD}", "g\u{308}", ""] }),
+
+    render_glyphs_from_bytes_code_100_700_18_true_1_8_hiragana_kanji,
+        Gray8,
+        1,
+        mplus!(code(100), 700, 18, true, 1, 8, 'ぁ'..='ゖ', ["東京", "京都", "g\u{308}"]),
+        mplus_bytes!(code(100), 700, 18, true, 1, 8, 'ぁ'..='ゖ', ["東京", "京都", "g\u{308}"]),
+        seq!(C in 'ぁ'..='ゖ' { [#(concat!(C),)* "東", "京", "都", "g\u{308}", "Q"] }),
+}
+
+macro_rules! test_charmap_tree_and_table {
+    (
+        $(
+            $fn_ident:ident, $tree:expr, $table:expr, $text_fragments:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let tree = $tree;
+                let table = $table;
+                let text_fragments = $text_fragments;
+
+                for slice in text_fragments {
+                    let entry = tree.get(slice);
+                    let result = table.get(slice);
+                    assert_eq!(entry.key(), result.key(), "\n slice: {slice:?}");
+                    assert_eq!(entry.advance_chars(), result.advance_chars(), "\n slice: {slice:?}");
+
+                    for index in 0..4 {
+                        assert_eq!(entry.glyph(index), result.glyph(index), "\n slice: {slice:?}");
+                    }
+                }
+            }
+        )*
+    }
+}
+
+test_charmap_tree_and_table! {
+    charmap_tree_and_table_1_500_25_false_1_4_kern_space_tilde_ligatures,
+        mplus!(font = 1, size = 25, charmap = tree, sources = [kern(' '..='~', ["ffi", "ffl"])]),
+        mplus!(font = 1, size = 25, charmap = table, sources = [kern(' '..='~', ["ffi", "ffl"])]),
+        seq!(C in ' '..='~' {
+            [#(concat!(C),)* "ffi", "ffl", "ff", "fi", "ffx", "AV", "\u{FFFD}", "g\u{308}", ""]
+        }),
+
+    charmap_tree_and_table_2_400_16_false_1_4_kanji,
+        mplus!(font = 2, size = 16, charmap = tree, sources = [["東京", "京都", "東"]]),
+        mplus!(font = 2, size = 16, sources = [["東京", "京都", "東"]]),
+        ["東京都", "京都", "京", "都", "東", "東西", "西", "", "Q"],
+}
+
+macro_rules! test_render_glyphs_with_compression {
+    (
+        $(
+            $fn_ident:ident, $positions:literal, $bitmap_font:expr, $compressed:expr, $text_fragments:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let bitmap_font = $bitmap_font;
+                let compressed = $compressed;
+                let text_fragments = $text_fragments;
+
+                for slice in text_fragments {
+                    let entry = bitmap_font.get(slice);
+                    let result = compressed.get(slice);
+                    for index in 0..4 {
+                        for position in 0..$positions {
+                            let image = entry.image(index, position);
+                            let result = result.image(index, position);
+                            assert_eq!(
+                                image.bounding_box(),
+                                result.bounding_box(),
+                                "\n slice: {slice:?}"
+                            );
+                            assert!(
+                                image.colors().into_iter().eq(result.colors()),
+                                "\n slice: {slice:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        )*
+    }
+}
+
+test_render_glyphs_with_compression! {
+    render_glyphs_with_compression_1_500_25_false_4_4_kern_space_tilde,
+        4,
+        mplus!(font = 1, weight = 500, size = 25, positions = 4, sources = [kern(' '..='~', [])]),
+        mplus!(
+            font = 1,
+            weight = 500,
+            size = 25,
+            positions = 4,
+            compress = rle,
+            sources = [kern(' '..='~', [])],
+        ),
+        seq!(C in ' '..='~' { [#(concat!(C),)* "\u{FFFD}", ""] }),
+
+    render_glyphs_with_compression_2_400_40_true_1_1_kanji,
+        1,
+        mplus!(2, 400, 40, true, 1, 1, ["東京", "京都", "g\u{308}"]),
+        mplus!(
+            font = 2,
+            size = 40,
+            hint = true,
+            bit_depth = 1,
+            compress = rle,
+            sources = [["東京", "京都", "g\u{308}"]],
+        ),
+        ["東", "京", "都", "g\u{308}", "Q"],
+
+    render_glyphs_with_compression_code_100_700_18_true_1_8_hiragana,
+        1,
+        mplus!(code(100), 700, 18, true, 1, 8, 'ぁ'..='ゖ'),
+        mplus!(font = code(100), weight = 700, size = 18, hint = true, bit_depth = 8, compress = rle, sources = ['ぁ'..='ゖ']),
+        seq!(C in 'ぁ'..='ゖ' { [#(concat!(C),)*] }),
+}
+
+macro_rules! test_render_glyphs_with_subpixel {
+    (
+        $(
+            $fn_ident:ident, $bitmap_font:expr, $rgb_font:expr, $bgr_font:expr, $text_fragments:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let bitmap_font = $bitmap_font;
+                let rgb_font = $rgb_font;
+                let bgr_font = $bgr_font;
+                let text_fragments = $text_fragments;
+                assert_eq!(bitmap_font.subpixel, None);
+                assert_eq!(rgb_font.subpixel, Some(SubpixelOrder::Rgb));
+                assert_eq!(bgr_font.subpixel, Some(SubpixelOrder::Bgr));
+
+                for slice in text_fragments {
+                    let entry = bitmap_font.get(slice);
+                    let rgb_entry = rgb_font.get(slice);
+                    let bgr_entry = bgr_font.get(slice);
+                    assert_eq!(
+                        entry.advance_width_to(""),
+                        rgb_entry.advance_width_to(""),
+                        "\n slice: {slice:?}"
+                    );
+
+                    let image_box = entry.image(0, 0).bounding_box();
+                    let rgb_image = rgb_entry.image(0, 0);
+                    let bgr_image = bgr_entry.image(0, 0);
+                    let rgb_image_box = rgb_image.bounding_box();
+                    assert_eq!(rgb_image_box, bgr_image.bounding_box(), "\n slice: {slice:?}");
+                    assert_eq!(rgb_image_box.size.width % 3, 0, "\n slice: {slice:?}");
+                    assert_eq!(image_box.size.height, rgb_image_box.size.height, "\n slice: {slice:?}");
+                    assert!(
+                        rgb_image.colors().into_iter().eq(bgr_image.colors()),
+                        "\n slice: {slice:?}"
+                    );
+                }
+            }
+        )*
+    }
+}
+
+test_render_glyphs_with_subpixel! {
+    render_glyphs_with_subpixel_1_400_16_false_1_4_kern_space_tilde,
+        mplus!(font = 1, size = 16, sources = [kern(' '..='~', [])]),
+        mplus!(font = 1, size = 16, subpixel = rgb, sources = [kern(' '..='~', [])]),
+        mplus!(font = 1, size = 16, subpixel = bgr, sources = [kern(' '..='~', [])]),
+        seq!(C in '!'..='~' { [#(concat!(C),)*] }),
+
+    render_glyphs_with_subpixel_code_100_500_12_true_1_8_digits,
+        mplus!(code(100), 500, 12, true, 1, 8, '0'..='9'),
+        mplus!(
+            font = code(100),
+            weight = 500,
+            size = 12,
+            hint = true,
+            bit_depth = 8,
+            subpixel = rgb,
+            sources = ['0'..='9'],
+        ),
+        mplus!(
+            font = code(100),
+            weight = 500,
+            size = 12,
+            hint = true,
+            bit_depth = 8,
+            subpixel = bgr,
+            sources = ['0'..='9'],
+        ),
+        seq!(C in '0'..='9' { [#(concat!(C),)*] }),
+}
+
+#[test]
+fn render_glyphs_with_full_widths() {
+    let bitmap_font = mplus!(font = 1, size = 16, sources = [kern('0'..='9', [])]);
+    let full_width_font = mplus!(
+        font = 1,
+        size = 16,
+        features = ["fwid"],
+        sources = [kern('0'..='9', [])]
+    );
+    let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+    for slice in digits {
+        let entry = bitmap_font.get(slice);
+        let full_width_entry = full_width_font.get(slice);
+        assert!(entry.advance_width_to("") < 12.0, "\n slice: {slice:?}");
+        assert_ne!(
+            full_width_entry.glyph(0),
+            entry.glyph(0),
+            "\n slice: {slice:?}"
+        );
+        for next in digits {
+            let advance_width = full_width_entry.advance_width_to(next);
+            assert_eq!(advance_width, 16.0, "\n slice: {slice:?}");
+        }
+    }
+}
+
+#[test]
+fn render_glyphs_without_kerning() {
+    let bitmap_font = mplus!(font = 2, size = 20, sources = [kern('A'..='Z', [])]);
+    let unkerned_font = mplus!(
+        font = 2,
+        size = 20,
+        features = ["-kern"],
+        sources = [kern('A'..='Z', [])]
+    );
+    let entry = bitmap_font.get("A");
+    assert!(entry.advance_width_to("V") < entry.advance_width_to(""));
+
+    let entry = unkerned_font.get("A");
+    assert_eq!(entry.advance_width_to("V"), entry.advance_width_to(""));
+    assert_eq!(
+        unkerned_font.get("V").image(0, 0).bounding_box(),
+        bitmap_font.get("V").image(0, 0).bounding_box()
+    );
+}
+
+macro_rules! test_render_glyphs_with_outline {
+    (
+        $(
+            $fn_ident:ident, $bitmap_font:expr, $outlined_font:expr, $text_fragments:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let bitmap_font = $bitmap_font;
+                let outlined_font = $outlined_font;
+                let text_fragments = $text_fragments;
+                for slice in text_fragments {
+                    let entry = bitmap_font.get(slice);
+                    let outlined_entry = outlined_font.get(slice);
+                    let image = entry.image(0, 0).mul_offset(1, -1);
+                    let image_box = image.bounding_box();
+                    assert_eq!(entry.outline_image(0, 0).bounding_box().size, Size::zero());
+                    assert_eq!(
+                        image_box,
+                        outlined_entry.image(0, 0).mul_offset(1, -1).bounding_box(),
+                        "\n slice: {slice:?}"
+                    );
+                    assert!(
+                        image.colors().into_iter().eq(outlined_entry.image(0, 0).colors()),
+                        "\n slice: {slice:?}"
+                    );
+
+                    let outline = outlined_entry.outline_image(0, 0).mul_offset(1, -1);
+                    let outline_box = outline.bounding_box();
+                    assert!(outline_box.contains(image_box.top_left), "\n slice: {slice:?}");
+                    assert!(
+                        image_box
+                            .bottom_right()
+                            .is_some_and(|point| outline_box.contains(point)),
+                        "\n slice: {slice:?}"
+                    );
+
+                    let coverage: Vec<_> = outline_box.points().zip(outline.colors()).collect();
+                    for (point, color) in image_box.points().zip(image.colors()) {
+                        let outline_color = coverage
+                            .iter()
+                            .find_map(|(other, color)| (*other == point).then_some(*color));
+                        assert!(
+                            outline_color.is_some_and(|outline_color| {
+                                outline_color.into_storage() >= color.into_storage()
+                            }),
+                            "\n slice: {slice:?}\n point: {point:?}"
+                        );
+                    }
+                }
+            }
+        )*
+    }
+}
+
+test_render_glyphs_with_outline! {
+    render_glyphs_with_outline_1_400_16_false_1_4_kern_space_tilde,
+        mplus!(font = 1, size = 16, sources = [kern(' '..='~', [])]),
+        mplus!(font = 1, size = 16, outline = 1, sources = [kern(' '..='~', [])]),
+        seq!(C in '!'..='~' { [#(concat!(C),)*] }),
+
+    render_glyphs_with_outline_code_100_700_20_true_1_8_hiragana,
+        mplus!(code(100), 700, 20, true, 1, 8, 'ぁ'..='ゖ'),
+        mplus!(
+            font = code(100),
+            weight = 700,
+            size = 20,
+            hint = true,
+            bit_depth = 8,
+            outline = 3,
+            sources = ['ぁ'..='ゖ'],
+        ),
+        seq!(C in 'ぁ'..='ゖ' { [#(concat!(C),)*] }),
+}
+
+macro_rules! test_render_glyphs_oblique {
+    (
+        $(
+            $fn_ident:ident, $bitmap_font:expr, $oblique_font:expr, $text:expr,
+        )*
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                let bitmap_font = $bitmap_font;
+                let oblique_font = $oblique_font;
+                for c in $text.chars() {
+                    let slice = c.encode_utf8(&mut [0; 4]).to_owned();
+                    let image = bitmap_font.get(&slice).image(0, 0);
+                    let oblique_image = oblique_font.get(&slice).image(0, 0);
+                    let [centroid, oblique_centroid] = [&image, &oblique_image].map(|image| {
+                        let points = image.bounding_box().points();
+                        let coverage = points.zip(image.colors()).map(|(point, color)| {
+                            (point.x * i32::from(color.into_storage()), color.into_storage())
+                        });
+                        let (moment, total) = coverage.fold((0, 0), |(moment, total), (x, value)| {
+                            (moment + x, total + u32::from(value))
+                        });
+
+                        moment as f32 / total as f32
+                    });
+                    assert!(oblique_centroid > centroid, "\n slice: {slice:?}");
+
+                    let image_box = image.bounding_box();
+                    let oblique_box = oblique_image.bounding_box();
+                    let [Some(bottom_right), Some(oblique_bottom_right)] =
+                        [image_box.bottom_right(), oblique_box.bottom_right()]
+                    else {
+                        panic!("expected glyph images\n slice: {slice:?}");
+                    };
+                    assert!(
+                        oblique_box.size.width >= image_box.size.width,
+                        "\n slice: {slice:?}"
+                    );
+                    assert!(
+                        oblique_box.top_left.y.abs_diff(image_box.top_left.y) <= 1,
+                        "\n slice: {slice:?}"
+                    );
+                    assert!(
+                        oblique_bottom_right.y.abs_diff(bottom_right.y) <= 1,
+                        "\n slice: {slice:?}"
+                    );
+                }
+
+                let position = Point::new(4, 30);
+                let character_style = BitmapFontStyle::new(&bitmap_font, Rgb888::WHITE);
+                let mut expected = MockDisplay::new();
+                let text = Text::new($text, position, character_style);
+                let next_position = text.draw(&mut expected).unwrap();
+
+                let character_style = BitmapFontStyle::new(&oblique_font, Rgb888::WHITE);
+                let mut display = MockDisplay::new();
+                let text = Text::new($text, position, character_style);
+                assert_eq!(text.draw(&mut display).unwrap(), next_position);
+                assert_eq!(display.affected_area().top_left.y, expected.affected_area().top_left.y);
+                assert!(
+                    display.affected_area().size.width >= expected.affected_area().size.width
+                );
+                assert_ne!(display, expected);
+            }
+        )*
+    }
+}
+
+test_render_glyphs_oblique! {
+    render_glyphs_oblique_1_400_16_false_1_4_uppercase,
+        mplus!(font = 1, size = 16, sources = [kern('A'..='Z', [])]),
+        mplus!(font = 1, size = 16, oblique = 12, sources = [kern('A'..='Z', [])]),
+        "HALT",
+
+    render_glyphs_oblique_2_700_20_true_4_8_uppercase,
+        mplus!(font = 2, weight = 700, size = 20, hint = true, positions = 4, bit_depth = 8, sources = [kern('A'..='Z', [])]),
+        mplus!(font = 2, weight = 700, size = 20, hint = true, positions = 4, bit_depth = 8, oblique = 15, sources = [kern('A'..='Z', [])]),
+        "WIND",
+}
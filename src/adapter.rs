@@ -1,10 +1,13 @@
 use embedded_graphics::Pixel;
 use embedded_graphics::draw_target::DrawTarget;
-use embedded_graphics::geometry::Dimensions;
+use embedded_graphics::geometry::{Dimensions, Point, Size};
 use embedded_graphics::pixelcolor::{BinaryColor, Gray2, Gray4, Gray8};
 use embedded_graphics::primitives::Rectangle;
 
 use crate::color::Colormap;
+use crate::framebuffer::ReadPixel;
+use crate::rect::{PointExt, RectangleExt};
+use crate::style::Rotation;
 
 /// Adapter draw target using a colormap.
 ///
@@ -16,6 +19,28 @@ pub struct ColormapAdapter<'a, D: DrawTarget, const N: usize> {
     colormap: &'a Colormap<D::Color, N>,
 }
 
+/// Adapter draw target with rotation.
+///
+/// This draw target rotates the pixels that are drawn on it about an origin before drawing them on
+/// another draw target.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RotatedAdapter<'a, D: DrawTarget> {
+    parent: &'a mut D,
+    origin: Point,
+    rotation: Rotation,
+}
+
+/// Adapter draw target with scaling.
+///
+/// This draw target scales the pixels that are drawn on it up about an origin, filling in a square
+/// block of pixels for each one on another draw target.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ScaledAdapter<'a, D: DrawTarget> {
+    parent: &'a mut D,
+    origin: Point,
+    scale: u32,
+}
+
 /// Extension trait for draw targets.
 pub trait DrawTargetExt: DrawTarget + Sized {
     /// Returns an adapter for the draw target with the specified colormap, for mapping gray values.
@@ -23,6 +48,14 @@ pub trait DrawTargetExt: DrawTarget + Sized {
         &'a mut self,
         colormap: &'a Colormap<Self::Color, N>,
     ) -> ColormapAdapter<'a, Self, N>;
+
+    /// Returns an adapter for the draw target with the specified origin and rotation, for drawing
+    /// rotated pixels.
+    fn rotated(&mut self, origin: Point, rotation: Rotation) -> RotatedAdapter<'_, Self>;
+
+    /// Returns an adapter for the draw target with the specified origin and scale, for drawing
+    /// scaled pixels.
+    fn scaled(&mut self, origin: Point, scale: u32) -> ScaledAdapter<'_, Self>;
 }
 
 impl<D: DrawTarget> DrawTargetExt for D {
@@ -32,6 +65,14 @@ impl<D: DrawTarget> DrawTargetExt for D {
     ) -> ColormapAdapter<'a, D, N> {
         ColormapAdapter::new(self, colormap)
     }
+
+    fn rotated(&mut self, origin: Point, rotation: Rotation) -> RotatedAdapter<'_, D> {
+        RotatedAdapter::new(self, origin, rotation)
+    }
+
+    fn scaled(&mut self, origin: Point, scale: u32) -> ScaledAdapter<'_, D> {
+        ScaledAdapter::new(self, origin, scale)
+    }
 }
 
 impl<'a, D: DrawTarget, const N: usize> ColormapAdapter<'a, D, N> {
@@ -97,3 +138,120 @@ impl_draw_target! {
     Gray4, 16,
     Gray8, 256,
 }
+
+impl<'a, D: DrawTarget> RotatedAdapter<'a, D> {
+    /// Creates a new adapter draw target with the specified parent draw target, origin, and
+    /// rotation.
+    pub const fn new(parent: &'a mut D, origin: Point, rotation: Rotation) -> Self {
+        Self {
+            parent,
+            origin,
+            rotation,
+        }
+    }
+}
+
+impl<D: DrawTarget> Dimensions for RotatedAdapter<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        let rotation = self.rotation.inverse();
+
+        self.parent
+            .bounding_box()
+            .rotate_about(self.origin, rotation)
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for RotatedAdapter<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let pixels = pixels.into_iter().map(|Pixel(pixel, color)| {
+            Pixel(self.rotation.rotate_about(pixel, self.origin), color)
+        });
+
+        self.parent.draw_iter(pixels)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.rotate_about(self.origin, self.rotation);
+
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<D: ReadPixel> ReadPixel for RotatedAdapter<'_, D> {
+    fn read_pixel(&self, point: Point) -> Option<Self::Color> {
+        self.parent
+            .read_pixel(self.rotation.rotate_about(point, self.origin))
+    }
+}
+
+impl<'a, D: DrawTarget> ScaledAdapter<'a, D> {
+    /// Creates a new adapter draw target with the specified parent draw target, origin, and scale,
+    /// which is at least one.
+    pub const fn new(parent: &'a mut D, origin: Point, scale: u32) -> Self {
+        let scale = if scale > 1 { scale } else { 1 };
+
+        Self {
+            parent,
+            origin,
+            scale,
+        }
+    }
+}
+
+impl<D: DrawTarget> Dimensions for ScaledAdapter<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent
+            .bounding_box()
+            .scale_down_about(self.origin, self.scale)
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for ScaledAdapter<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        if self.scale == 1 {
+            return self.parent.draw_iter(pixels);
+        }
+
+        let size = Size::new_equal(self.scale);
+        for Pixel(pixel, color) in pixels {
+            let top_left = pixel.scale_about(self.origin, self.scale);
+            self.parent
+                .fill_solid(&Rectangle { top_left, size }, color)?;
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.scale_about(self.origin, self.scale);
+
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<D: ReadPixel> ReadPixel for ScaledAdapter<'_, D> {
+    fn read_pixel(&self, point: Point) -> Option<Self::Color> {
+        self.parent
+            .read_pixel(point.scale_about(self.origin, self.scale))
+    }
+}
@@ -0,0 +1,625 @@
+//! Bitmap fonts in a serialized binary format.
+//!
+//! The [`mplus_bytes!`](mplusfonts_macros::mplus_bytes) macro takes the same arguments as
+//! [`mplus!`](mplusfonts_macros::mplus), but instead of a data structure that has to be compiled
+//! into the program, it expands to a byte string literal. The bytes can be stored anywhere, for
+//! example in a separate flash partition, or be updated over the air, and then be read using a
+//! [`BitmapFontBlob`] without copying.
+//!
+//! ```
+//! # use embedded_graphics::pixelcolor::{Gray4, Rgb888};
+//! # use embedded_graphics::prelude::*;
+//! # use mplusfonts::blob::BitmapFontBlob;
+//! # use mplusfonts::mplus_bytes;
+//! # use mplusfonts::style::BitmapFontStyle;
+//! #
+//! static BYTES: &[u8] = mplus_bytes!(font = 2, size = 16, positions = 2, sources = ["Hello"]);
+//!
+//! let bitmap_font = BitmapFontBlob::<Gray4, 2>::new(BYTES).expect("expected bitmap font blob");
+//! let character_style = BitmapFontStyle::new(&bitmap_font, Rgb888::WHITE);
+//! ```
+//!
+//! # Format
+//!
+//! All integers and floating-point numbers are little-endian, and all offsets are in bytes from the
+//! start of the blob. There are no alignment requirements.
+//!
+//! | Offset | Size | Field                                                                    |
+//! |--------|------|--------------------------------------------------------------------------|
+//! | 0      | 4    | The magic number, [`MAGIC`].                                             |
+//! | 4      | 2    | The version of the format, [`VERSION`].                                  |
+//! | 6      | 1    | The bit depth, that is, bits per pixel.                                  |
+//! | 7      | 1    | The number of positions, that is, the const generic parameter `N`.       |
+//! | 8      | 28   | The metrics: `top`, `ascender`, ..., `bottom` as 7 × `f32`.              |
+//! | 36     | 8    | The underline decoration: `offset`, `height` as 2 × `f32`.               |
+//! | 44     | 8    | The strikethrough decoration: `offset`, `height` as 2 × `f32`.           |
+//! | 52     | 4    | The number of charmap entries, not counting the `.notdef` entry.         |
+//! | 56     | 4    | The offset to the table of charmap entries.                              |
+//! | 60     | 2    | The maximum number of characters in a key.                               |
+//! | 62     | 2    | Reserved.                                                                |
+//! | 64     | 4    | The number of composition table entries.                                 |
+//! | 68     | 4    | The offset to the composition table.                                     |
+//!
+//! The table of charmap entries is sorted by key and is followed by the `.notdef` entry, which is
+//! used for any input that does not match a key. Each charmap entry is 28 bytes long:
+//!
+//! | Offset | Size | Field                                                                    |
+//! |--------|------|--------------------------------------------------------------------------|
+//! | 0      | 4    | The offset to the UTF-8 encoded key.                                     |
+//! | 4      | 2    | The length of the key.                                                   |
+//! | 6      | 2    | The value for advancing the position in the text run.                    |
+//! | 8      | 4    | The default value for advancing the position along the _x_-axis.         |
+//! | 12     | 4    | The offset to the kerning table, which is sorted by key.                 |
+//! | 16     | 4    | The number of kerning table entries, each 12 bytes long.                 |
+//! | 20     | 4    | The offset to the glyph table.                                           |
+//! | 24     | 2    | The number of glyph table entries, each 16 bytes long.                   |
+//! | 26     | 2    | Reserved.                                                                |
+//!
+//! A kerning table entry holds the offset and the length of the key for the next charmap entry as
+//! 2 × `u32`, followed by the value for advancing the position along the _x_-axis as `f32`.
+//!
+//! A glyph table entry holds the glyph identifier and the number of images as 2 × `u16`, followed
+//! by the _x_- and _y_-offsets as 2 × `f32`, and the offset to the image table as `u32`. An image
+//! table entry is 20 bytes long, holding the left and top offsets of the image as 2 × `i32`,
+//! followed by the width of the image, the offset to the image data, and the length of the image
+//! data as 3 × `u32`.
+//!
+//! The composition table lists the characters in composed form that have charmap entries, for
+//! matching them in decomposed form, and is sorted by the characters in decomposed form. Each
+//! composition table entry is 16 bytes long, holding the offset and the length of the character in
+//! decomposed form, followed by the offset and the length of the key for the charmap entry, as
+//! 4 × `u32`.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+
+use embedded_graphics::geometry::Point;
+use embedded_graphics::iterator::raw::RawDataSlice;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::pixelcolor::raw::{BigEndian, RawData};
+
+use crate::charmap::{CharmapEntryData, CharmapEntryKey};
+use crate::color::SubpixelOrder;
+use crate::font::BitmapFontData;
+use crate::glyph::GlyphId;
+use crate::image::{Image, ImageData, ImageRaw};
+use crate::metrics::{BitmapFontMetrics, DecorationDimensions};
+
+/// The magic number at the start of every bitmap font blob.
+pub const MAGIC: [u8; 4] = *b"MPLF";
+
+/// The version of the format that this crate reads and writes.
+pub const VERSION: u16 = 2;
+
+const HEADER_SIZE: usize = 72;
+const ENTRY_SIZE: usize = 28;
+const COMPOSITION_SIZE: usize = 16;
+const KERNING_SIZE: usize = 12;
+const GLYPH_SIZE: usize = 16;
+const IMAGE_SIZE: usize = 20;
+
+/// Error that occurs when a byte slice cannot be read as a bitmap font blob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BlobError {
+    /// The byte slice does not start with the magic number.
+    Magic,
+    /// The version of the format is not supported.
+    Version(u16),
+    /// The bit depth does not match the color type.
+    BitDepth(u8),
+    /// The number of positions does not match the const generic parameter `N`.
+    Positions(u8),
+    /// The byte slice is too short for the header, for the table of charmap entries, or for the
+    /// composition table.
+    Truncated,
+}
+
+/// Bitmap font that reads its glyph data from a byte slice.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BitmapFontBlob<'a, C, const N: usize>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    bytes: &'a [u8],
+    entries: &'a [u8],
+    compositions: &'a [u8],
+    max_key_chars: usize,
+    metrics: BitmapFontMetrics,
+    underline: DecorationDimensions,
+    strikethrough: DecorationDimensions,
+    phantom: PhantomData<&'a C>,
+}
+
+/// Charmap entry of a bitmap font blob.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BlobEntry<'a, C, const N: usize>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    bytes: &'a [u8],
+    record: &'a [u8],
+    phantom: PhantomData<&'a C>,
+}
+
+impl<'a, C, const N: usize> BitmapFontBlob<'a, C, N>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    /// Creates a new bitmap font from the specified byte slice, checking the header and the bounds
+    /// of the table of charmap entries and of the composition table.
+    pub const fn new(bytes: &'a [u8]) -> Result<Self, BlobError> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(BlobError::Truncated);
+        }
+
+        if read_u32(bytes, 0) != u32::from_le_bytes(MAGIC) {
+            return Err(BlobError::Magic);
+        }
+
+        let version = read_u16(bytes, 4);
+        if version != VERSION {
+            return Err(BlobError::Version(version));
+        }
+
+        let bit_depth = bytes[6];
+        if bit_depth as usize != C::Raw::BITS_PER_PIXEL {
+            return Err(BlobError::BitDepth(bit_depth));
+        }
+
+        let positions = bytes[7];
+        if positions as usize != N {
+            return Err(BlobError::Positions(positions));
+        }
+
+        let entry_count = read_u32(bytes, 52) as usize;
+        let entries_offset = read_u32(bytes, 56) as usize;
+        let length = entry_count.saturating_add(1).saturating_mul(ENTRY_SIZE);
+        let Some(entries) = slice(bytes, entries_offset, length) else {
+            return Err(BlobError::Truncated);
+        };
+
+        let composition_count = read_u32(bytes, 64) as usize;
+        let compositions_offset = read_u32(bytes, 68) as usize;
+        let length = composition_count.saturating_mul(COMPOSITION_SIZE);
+        let Some(compositions) = slice(bytes, compositions_offset, length) else {
+            return Err(BlobError::Truncated);
+        };
+
+        let metrics = BitmapFontMetrics {
+            top: read_f32(bytes, 8),
+            ascender: read_f32(bytes, 12),
+            cap_height: read_f32(bytes, 16),
+            x_height: read_f32(bytes, 20),
+            baseline: read_f32(bytes, 24),
+            descender: read_f32(bytes, 28),
+            bottom: read_f32(bytes, 32),
+        };
+        let underline = DecorationDimensions {
+            offset: read_f32(bytes, 36),
+            height: read_f32(bytes, 40),
+        };
+        let strikethrough = DecorationDimensions {
+            offset: read_f32(bytes, 44),
+            height: read_f32(bytes, 48),
+        };
+        let blob = Self {
+            bytes,
+            entries,
+            compositions,
+            max_key_chars: read_u16(bytes, 60) as usize,
+            metrics,
+            underline,
+            strikethrough,
+            phantom: PhantomData,
+        };
+
+        Ok(blob)
+    }
+
+    /// Returns the number of charmap entries, not counting the `.notdef` entry.
+    pub const fn len(&self) -> usize {
+        self.entries.len() / ENTRY_SIZE - 1
+    }
+
+    /// Returns `true` if there are no charmap entries other than the `.notdef` entry.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the charmap entry at the specified index, or the `.notdef` entry if out of bounds.
+    fn entry(&self, index: usize) -> BlobEntry<'a, C, N> {
+        let index = index.min(self.len());
+        let offset = index * ENTRY_SIZE;
+
+        BlobEntry {
+            bytes: self.bytes,
+            record: &self.entries[offset..offset + ENTRY_SIZE],
+            phantom: PhantomData,
+        }
+    }
+
+    /// Finds the charmap entry with the specified key using binary search.
+    fn find(&self, key: &str) -> Option<BlobEntry<'a, C, N>> {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let entry = self.entry(middle);
+            match entry.key().cmp(key) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return Some(entry),
+            }
+        }
+
+        None
+    }
+
+    /// Finds the longest character in decomposed form that the specified string slice starts with
+    /// in the composition table, returning it along with the key of the charmap entry for the
+    /// character in composed form, or `None` if there is none.
+    fn composition(&self, slice: &str) -> Option<(&'a str, CharmapEntryKey<'a>)> {
+        let first = slice.chars().next()?;
+        let count = self.compositions.len() / COMPOSITION_SIZE;
+        let string = |index: usize, field: usize| {
+            let offset = index * COMPOSITION_SIZE + field;
+            let string_offset = read_u32(self.compositions, offset) as usize;
+            let string_length = read_u32(self.compositions, offset + 4) as usize;
+
+            read_str(self.bytes, string_offset, string_length)
+        };
+        let first_of = |index| string(index, 0).chars().next().unwrap_or_default();
+        let start = partition_point(0, count, |index| first_of(index) < first);
+        let end = partition_point(start, count, |index| first_of(index) == first);
+        let index = (start..end)
+            .rev()
+            .find(|index| slice.starts_with(string(*index, 0)))?;
+
+        Some((string(index, 0), string(index, 8)))
+    }
+}
+
+impl<'a, C, const N: usize> BitmapFontData<'a, C, N> for BitmapFontBlob<'a, C, N>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    type Entry<'b>
+        = BlobEntry<'a, C, N>
+    where
+        Self: 'b;
+
+    fn get(&self, slice: &str) -> Self::Entry<'_> {
+        let chars = slice.chars().take(self.max_key_chars).count();
+        for chars in (1..=chars).rev() {
+            let end = slice
+                .char_indices()
+                .nth(chars)
+                .map_or(slice.len(), |(end, _)| end);
+            if let Some(entry) = self.find(&slice[..end]) {
+                return entry;
+            }
+        }
+
+        self.entry(self.len())
+    }
+
+    fn get_normalized(&self, slice: &str) -> (Self::Entry<'_>, usize) {
+        let entry = self.get(slice);
+        let Some((decomposed, key)) = self.composition(slice) else {
+            return (entry, entry.advance_chars());
+        };
+
+        let count = decomposed.chars().count();
+        if entry.advance_chars() >= count {
+            return (entry, entry.advance_chars());
+        }
+
+        (self.get(key), count)
+    }
+
+    fn metrics(&self) -> BitmapFontMetrics {
+        self.metrics
+    }
+
+    fn underline(&self) -> DecorationDimensions {
+        self.underline
+    }
+
+    fn strikethrough(&self) -> DecorationDimensions {
+        self.strikethrough
+    }
+
+    fn subpixel(&self) -> Option<SubpixelOrder> {
+        None
+    }
+}
+
+impl<'a, C, const N: usize> BlobEntry<'a, C, N>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    /// Returns the glyph table entry at the specified index, if in bounds.
+    fn glyph_record(&self, index: usize) -> Option<&'a [u8]> {
+        let glyph_count = read_u16(self.record, 24) as usize;
+        if index >= glyph_count {
+            return None;
+        }
+
+        let offset = read_u32(self.record, 20) as usize;
+        let offset = offset.saturating_add(index * GLYPH_SIZE);
+
+        slice(self.bytes, offset, GLYPH_SIZE)
+    }
+}
+
+impl<'a, C, const N: usize> CharmapEntryData<'a, C, N> for BlobEntry<'a, C, N>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    fn key(&self) -> CharmapEntryKey<'a> {
+        let offset = read_u32(self.record, 0) as usize;
+        let length = read_u16(self.record, 4) as usize;
+
+        read_str(self.bytes, offset, length)
+    }
+
+    fn advance_chars(&self) -> usize {
+        read_u16(self.record, 6) as usize
+    }
+
+    fn advance_width_to(&self, key: CharmapEntryKey<'a>) -> f32 {
+        let offset = read_u32(self.record, 12) as usize;
+        let count = read_u32(self.record, 16) as usize;
+        let Some(table) = slice(self.bytes, offset, count.saturating_mul(KERNING_SIZE)) else {
+            return read_f32(self.record, 8);
+        };
+
+        let mut low = 0;
+        let mut high = count;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let offset = middle * KERNING_SIZE;
+            let key_offset = read_u32(table, offset) as usize;
+            let key_length = read_u32(table, offset + 4) as usize;
+            match read_str(self.bytes, key_offset, key_length).cmp(key) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return read_f32(table, offset + 8),
+            }
+        }
+
+        read_f32(self.record, 8)
+    }
+
+    fn advance_height(&self) -> f32 {
+        0.0
+    }
+
+    fn glyph(&self, index: usize) -> Option<(GlyphId, f32, f32)> {
+        let record = self.glyph_record(index)?;
+
+        Some((
+            read_u16(record, 0),
+            read_f32(record, 4),
+            read_f32(record, 8),
+        ))
+    }
+
+    fn image(&self, index: usize, position: usize) -> Image<ImageData<'a, C>> {
+        let Some(record) = self.glyph_record(index) else {
+            return Image::NULL;
+        };
+
+        let Some(index) = position.checked_rem(read_u16(record, 2) as usize) else {
+            return Image::NULL;
+        };
+
+        let offset = read_u32(record, 12) as usize;
+        let offset = offset.saturating_add(index * IMAGE_SIZE);
+        let Some(record) = slice(self.bytes, offset, IMAGE_SIZE) else {
+            return Image::NULL;
+        };
+
+        let left = read_i32(record, 0);
+        let top = read_i32(record, 4);
+        let width = read_u32(record, 8);
+        let offset = read_u32(record, 12) as usize;
+        let length = read_u32(record, 16) as usize;
+        let Some(data) = slice(self.bytes, offset, length) else {
+            return Image::NULL;
+        };
+
+        Image::new(
+            ImageData::Raw(ImageRaw::new(data, width)),
+            Point::new(left, top),
+        )
+    }
+
+    fn outline_image(&self, _: usize, _: usize) -> Image<ImageData<'a, C>> {
+        Image::NULL
+    }
+}
+
+impl fmt::Display for BlobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Magic => write!(f, "expected bitmap font blob"),
+            Self::Version(version) => write!(f, "expected version {VERSION}, found {version}"),
+            Self::BitDepth(bit_depth) => write!(f, "unexpected bit depth {bit_depth}"),
+            Self::Positions(positions) => write!(f, "unexpected number of positions {positions}"),
+            Self::Truncated => write!(f, "unexpected end of bitmap font blob"),
+        }
+    }
+}
+
+impl core::error::Error for BlobError {}
+
+/// Returns the index of the first element in the specified range for which the predicate returns
+/// `false`, assuming that it returns `true` for all elements before that one.
+fn partition_point(start: usize, end: usize, mut predicate: impl FnMut(usize) -> bool) -> usize {
+    let mut low = start;
+    let mut high = end;
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if predicate(middle) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+/// Returns the subslice at the specified offset and of the specified length, if in bounds.
+const fn slice(bytes: &[u8], offset: usize, length: usize) -> Option<&[u8]> {
+    let Some((_, bytes)) = bytes.split_at_checked(offset) else {
+        return None;
+    };
+
+    match bytes.split_at_checked(length) {
+        Some((bytes, _)) => Some(bytes),
+        None => None,
+    }
+}
+
+/// Reads the bytes at the specified offset, falling back to zeros if out of bounds.
+const fn read<const L: usize>(bytes: &[u8], offset: usize) -> [u8; L] {
+    let Some(bytes) = slice(bytes, offset, L) else {
+        return [0; L];
+    };
+
+    match bytes.first_chunk() {
+        Some(chunk) => *chunk,
+        None => [0; L],
+    }
+}
+
+const fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(read(bytes, offset))
+}
+
+const fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(read(bytes, offset))
+}
+
+const fn read_i32(bytes: &[u8], offset: usize) -> i32 {
+    i32::from_le_bytes(read(bytes, offset))
+}
+
+const fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes(read(bytes, offset))
+}
+
+/// Reads the string slice at the specified offset, falling back to an empty string if either out
+/// of bounds or not valid UTF-8.
+fn read_str(bytes: &[u8], offset: usize, length: usize) -> &str {
+    let bytes = slice(bytes, offset, length).unwrap_or_default();
+
+    core::str::from_utf8(bytes).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::pixelcolor::Gray4;
+
+    use super::*;
+    use crate::mplus_bytes;
+
+    const fn header(magic: [u8; 4], version: u16, bit_depth: u8, positions: u8) -> [u8; 104] {
+        let mut bytes = [0; 104];
+        let [a, b, c, d] = magic;
+        let [e, f] = version.to_le_bytes();
+        bytes[0] = a;
+        bytes[1] = b;
+        bytes[2] = c;
+        bytes[3] = d;
+        bytes[4] = e;
+        bytes[5] = f;
+        bytes[6] = bit_depth;
+        bytes[7] = positions;
+        bytes[56] = HEADER_SIZE as u8;
+
+        bytes
+    }
+
+    macro_rules! test_blob_new {
+        (
+            $(
+                $fn_ident:ident, $bytes:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let result = BitmapFontBlob::<Gray4, 2>::new($bytes).map(|blob| blob.len());
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_blob_new! {
+        blob_new_empty, &header(MAGIC, VERSION, 4, 2), Ok(0),
+        blob_new_magic, &header(*b"MPLU", VERSION, 4, 2), Err(BlobError::Magic),
+        blob_new_version, &header(MAGIC, 1, 4, 2), Err(BlobError::Version(1)),
+        blob_new_bit_depth, &header(MAGIC, VERSION, 8, 2), Err(BlobError::BitDepth(8)),
+        blob_new_positions, &header(MAGIC, VERSION, 4, 1), Err(BlobError::Positions(1)),
+        blob_new_truncated_header, &header(MAGIC, VERSION, 4, 2)[..60], Err(BlobError::Truncated),
+        blob_new_truncated_entries, &header(MAGIC, VERSION, 4, 2)[..80], Err(BlobError::Truncated),
+    }
+
+    #[test]
+    fn blob_new_truncated_compositions() {
+        let mut bytes = header(MAGIC, VERSION, 4, 2);
+        bytes[64] = 1;
+        bytes[68] = 96;
+        let result = BitmapFontBlob::<Gray4, 2>::new(&bytes).map(|blob| blob.len());
+        assert_eq!(result, Err(BlobError::Truncated));
+    }
+
+    #[test]
+    fn blob_get_normalized() {
+        let bytes = mplus_bytes!(font = 2, size = 10, sources = ["eéệ"]);
+        let blob = BitmapFontBlob::<Gray4, 1>::new(bytes).expect("expected bitmap font blob");
+        for (slice, expected_key, expected_count) in [
+            ("éa", "é", 1),
+            ("e\u{301}a", "é", 2),
+            ("e\u{323}\u{302}", "ệ", 3),
+            ("e\u{300}", "e", 1),
+        ] {
+            let (entry, count) = blob.get_normalized(slice);
+            assert_eq!(entry.key(), expected_key, "\n slice: {slice:?}");
+            assert_eq!(count, expected_count, "\n slice: {slice:?}");
+        }
+    }
+
+    #[test]
+    fn blob_unsupported_chars() {
+        let bytes = mplus_bytes!(font = 2, size = 10, sources = ["Hello"]);
+        let blob = BitmapFontBlob::<Gray4, 1>::new(bytes).expect("expected bitmap font blob");
+        assert!(blob.unsupported_chars("Hello!").eq([(5, '!')]));
+        assert!(blob.covers("Hello"));
+        assert!(!blob.covers("Hello!"));
+    }
+
+    #[test]
+    fn blob_get_notdef() {
+        let bytes = header(MAGIC, VERSION, 4, 2);
+        let blob = BitmapFontBlob::<Gray4, 2>::new(&bytes).expect("expected bitmap font blob");
+        let entry = blob.get("A");
+        assert_eq!(entry.key(), "");
+        assert_eq!(entry.glyph(0), None);
+        assert_eq!(entry.advance_width_to("B"), 0.0);
+    }
+}
@@ -1,28 +1,34 @@
+use core::marker::PhantomData;
+
+use embedded_graphics::geometry::Point;
 use embedded_graphics::iterator::raw::RawDataSlice;
 use embedded_graphics::pixelcolor::raw::BigEndian;
 use embedded_graphics::pixelcolor::{BinaryColor, PixelColor};
 use embedded_graphics::text::DecorationColor;
 
+use crate::charmap::CharmapEntryData as _;
 use crate::color::{Invert, Screen};
-use crate::font::BitmapFont;
-use crate::style::BitmapFontStyle;
+use crate::font::{BitmapFont, BitmapFontData};
+use crate::style::{BitmapFontStyle, Rotation};
 
 /// Builder for a style using a bitmap font.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct BitmapFontStyleBuilder<'a, 'b, T, C, const N: usize>
+pub struct BitmapFontStyleBuilder<'a, 'b, T, C, const N: usize, F = BitmapFont<'a, C, N>>
 where
     C: PixelColor + From<C::Raw>,
     T: PixelColor + Default + Invert + Screen,
+    F: BitmapFontData<'a, C, N>,
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
-    style: BitmapFontStyle<'a, 'b, T, C, N>,
+    pub(crate) style: BitmapFontStyle<'a, 'b, T, C, N, F>,
 }
 
-impl<'a, 'b, T, C, const N: usize> BitmapFontStyleBuilder<'a, 'b, T, C, N>
+impl<'a, 'b, T, C, const N: usize, F> BitmapFontStyleBuilder<'a, 'b, T, C, N, F>
 where
     C: PixelColor + From<C::Raw>,
     T: PixelColor + Default + Invert + Screen,
+    F: BitmapFontData<'a, C, N>,
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
     /// Resets the text color to the default value for the style.
@@ -49,6 +55,38 @@ where
         self
     }
 
+    /// Resets the outline color, so that the outline layer is not drawn.
+    pub const fn reset_outline_color(mut self) -> Self {
+        self.style.outline_color = None;
+        self
+    }
+
+    /// Removes the drop shadow.
+    pub const fn reset_shadow(mut self) -> Self {
+        self.style.shadow_color = None;
+        self.style.shadow_offset = Point::zero();
+        self
+    }
+
+    /// Draws all characters using their own advance widths.
+    pub const fn reset_tabular_chars(mut self) -> Self {
+        self.style.tabular_chars = "";
+        self
+    }
+
+    /// Removes the extra space between glyph clusters and after space characters.
+    pub const fn reset_spacing(mut self) -> Self {
+        self.style.letter_spacing = 0.0;
+        self.style.word_spacing = 0.0;
+        self
+    }
+
+    /// Resets the line height to that of the bitmap font.
+    pub const fn reset_line_height(mut self) -> Self {
+        self.style.line_height = None;
+        self
+    }
+
     /// Enables underline using the text color.
     pub const fn underline(mut self) -> Self {
         self.style.underline_color = DecorationColor::TextColor;
@@ -73,6 +111,85 @@ where
         self
     }
 
+    /// Sets the outline color to the specified value, so that the outline layer is drawn under
+    /// the glyphs of bitmap fonts that have been rendered with outlines.
+    pub const fn outline_color(mut self, outline_color: T) -> Self {
+        self.style.outline_color = Some(outline_color);
+        self
+    }
+
+    /// Enables a drop shadow using the specified color, drawn under the glyphs at the specified
+    /// offset.
+    pub const fn shadow(mut self, shadow_color: T, dx: i32, dy: i32) -> Self {
+        self.style.shadow_color = Some(shadow_color);
+        self.style.shadow_offset = Point::new(dx, dy);
+        self
+    }
+
+    /// Sets the rotation of the text about its position to the specified value.
+    pub const fn rotation(mut self, rotation: Rotation) -> Self {
+        self.style.rotation = rotation;
+        self
+    }
+
+    /// Sets the maximum number of digits that are drawn side by side, upright, in vertical writing
+    /// mode, which is called _tate-chū-yoko_. Runs of more digits are drawn sideways instead.
+    pub const fn tate_chu_yoko(mut self, digits: u8) -> Self {
+        self.style.tate_chu_yoko = digits;
+        self
+    }
+
+    /// Enables tabular figures, drawing each digit from `0` to `9` centered in a cell of the same
+    /// width.
+    pub const fn tabular_figures(self) -> Self {
+        self.tabular_chars("0123456789")
+    }
+
+    /// Draws each of the specified characters centered in a cell of the same width, which is the
+    /// greatest advance width among them. Kerning is not applied next to these characters.
+    pub const fn tabular_chars(mut self, chars: &'b str) -> Self {
+        self.style.tabular_chars = chars;
+        self
+    }
+
+    /// Sets the extra space after each glyph cluster, in pixels, which can be a fraction of a
+    /// pixel or negative.
+    pub const fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.style.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the extra space after each space character, in pixels, which is added to the letter
+    /// spacing.
+    pub const fn word_spacing(mut self, word_spacing: f32) -> Self {
+        self.style.word_spacing = word_spacing;
+        self
+    }
+
+    /// Sets the line height in pixels. The background is filled in for this height, with the
+    /// difference from the line height of the bitmap font split evenly above and below the text.
+    pub const fn line_height(mut self, line_height: u32) -> Self {
+        self.style.line_height = Some(line_height);
+        self
+    }
+
+    /// Sets the factor by which the text is scaled up, drawing each pixel of the glyphs, the
+    /// background, and the decorations as a square block of pixels. The metrics and the advance
+    /// widths of the bitmap font are scaled by the same factor, as are the shadow offset, the
+    /// spacing, and the line height that are set on the style.
+    pub const fn scale(mut self, scale: u32) -> Self {
+        self.style.scale = scale;
+        self
+    }
+
+    /// Sets the suffix that is drawn in place of the characters that are cut off from truncated
+    /// text, which is `…` by default. Three periods are drawn instead if the bitmap font does not
+    /// have the glyphs for it.
+    pub const fn ellipsis(mut self, ellipsis: &'static str) -> Self {
+        self.style.ellipsis = ellipsis;
+        self
+    }
+
     /// Enables underline using a custom color.
     pub const fn underline_with_color(mut self, underline_color: T) -> Self {
         self.style.underline_color = DecorationColor::Custom(underline_color);
@@ -86,13 +203,15 @@ where
     }
 
     /// Consumes the builder, returning a new one that is using the specified bitmap font.
-    pub const fn font<'z, D, const M: usize>(
+    pub const fn font<'y, 'z, D, const M: usize, G>(
         self,
-        font: &'z BitmapFont<'a, D, M>,
-    ) -> BitmapFontStyleBuilder<'a, 'z, T, D, M>
+        font: &'z G,
+    ) -> BitmapFontStyleBuilder<'y, 'z, T, D, M, G>
     where
+        'b: 'z,
         D: PixelColor + From<D::Raw>,
-        RawDataSlice<'a, D::Raw, BigEndian>: IntoIterator<Item = D::Raw>,
+        G: BitmapFontData<'y, D, M>,
+        RawDataSlice<'y, D::Raw, BigEndian>: IntoIterator<Item = D::Raw>,
     {
         BitmapFontStyleBuilder {
             style: BitmapFontStyle {
@@ -101,16 +220,52 @@ where
                 background_color: self.style.background_color,
                 underline_color: self.style.underline_color,
                 strikethrough_color: self.style.strikethrough_color,
+                outline_color: self.style.outline_color,
+                shadow_color: self.style.shadow_color,
+                shadow_offset: self.style.shadow_offset,
+                rotation: self.style.rotation,
+                tate_chu_yoko: self.style.tate_chu_yoko,
+                tabular_chars: self.style.tabular_chars,
+                tabular_width: self.style.tabular_width,
+                letter_spacing: self.style.letter_spacing,
+                word_spacing: self.style.word_spacing,
+                line_height: self.style.line_height,
+                scale: self.style.scale,
+                ellipsis: self.style.ellipsis,
+                phantom: PhantomData,
             },
         }
     }
 
-    /// Consumes the builder, returning the style.
-    pub const fn build(self) -> BitmapFontStyle<'a, 'b, T, C, N> {
+    /// Consumes the builder, returning the style with the width of the cells for the tabular
+    /// characters looked up in the bitmap font.
+    pub fn build(mut self) -> BitmapFontStyle<'a, 'b, T, C, N, F> {
+        let font = self.style.font;
+        let widths = self.style.tabular_chars.chars().map(|char| {
+            let mut bytes = [0; 4];
+            let slice = char.encode_utf8(&mut bytes);
+
+            font.get(slice).advance_width_to(Default::default())
+        });
+        self.style.tabular_width = widths.reduce(f32::max).unwrap_or_default();
         self.style
     }
 }
 
+impl<'a, T, C, const N: usize, F> Clone for BitmapFontStyleBuilder<'a, '_, T, C, N, F>
+where
+    C: PixelColor + From<C::Raw>,
+    T: PixelColor + Default + Invert + Screen,
+    F: BitmapFontData<'a, C, N>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            style: self.style.clone(),
+        }
+    }
+}
+
 impl<T> BitmapFontStyleBuilder<'_, '_, T, BinaryColor, 0>
 where
     T: PixelColor + Default + Invert + Screen,
@@ -124,6 +279,19 @@ where
                 background_color: None,
                 underline_color: DecorationColor::None,
                 strikethrough_color: DecorationColor::None,
+                outline_color: None,
+                shadow_color: None,
+                shadow_offset: Point::zero(),
+                rotation: Rotation::Deg0,
+                tate_chu_yoko: 0,
+                tabular_chars: "",
+                tabular_width: 0.0,
+                letter_spacing: 0.0,
+                word_spacing: 0.0,
+                line_height: None,
+                scale: 1,
+                ellipsis: "…",
+                phantom: PhantomData,
             },
         }
     }
@@ -143,6 +311,19 @@ where
                 background_color: None,
                 underline_color: DecorationColor::None,
                 strikethrough_color: DecorationColor::None,
+                outline_color: None,
+                shadow_color: None,
+                shadow_offset: Point::zero(),
+                rotation: Rotation::Deg0,
+                tate_chu_yoko: 0,
+                tabular_chars: "",
+                tabular_width: 0.0,
+                letter_spacing: 0.0,
+                word_spacing: 0.0,
+                line_height: None,
+                scale: 1,
+                ellipsis: "…",
+                phantom: PhantomData,
             },
         }
     }
@@ -2,7 +2,8 @@ use embedded_graphics::iterator::raw::RawDataSlice;
 use embedded_graphics::pixelcolor::PixelColor;
 use embedded_graphics::pixelcolor::raw::BigEndian;
 
-use crate::glyph::Glyph;
+use crate::glyph::{Glyph, GlyphId};
+use crate::image::{Image, ImageData};
 
 /// Key that is unique to a charmap entry in a bitmap font.
 pub type CharmapEntryKey<'a> = &'a str;
@@ -23,6 +24,9 @@ where
     /// the position along the _x_-axis for the charmap entry in order to get to the position of
     /// the next charmap entry.
     pub advance_width_to: fn(CharmapEntryKey<'a>) -> f32,
+    /// The value for advancing the position along the _y_-axis for the charmap entry in vertical
+    /// writing mode, which is zero for bitmap fonts that have been rendered without `vertical`.
+    pub advance_height: f32,
     /// The glyph.
     pub glyph: Glyph<'a, C, N>,
 }
@@ -30,6 +34,7 @@ where
 /// Charmap, for looking up glyph data, matching as many characters as possible at a time.
 #[derive(Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum Charmap<'a, C, const N: usize>
 where
     C: PixelColor + From<C::Raw>,
@@ -40,6 +45,35 @@ where
     /// A branch node with a function that takes the next character to match, returning another
     /// charmap.
     Branch(fn(char) -> &'a Charmap<'a, C, N>),
+    /// A table node with charmap entries sorted by key, for lookups using binary search.
+    Table(CharmapTable<'a, C, N>),
+}
+
+/// Charmap table, for looking up charmap entries by key using binary search.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CharmapTable<'a, C, const N: usize>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    /// The first character of the key of each charmap entry, in the same order.
+    pub chars: &'a [char],
+    /// The charmap entries, sorted by key.
+    pub entries: &'a [CharmapEntry<'a, C, N>],
+    /// The charmap entry for any input that does not start with any of the keys.
+    pub notdef: CharmapEntry<'a, C, N>,
+}
+
+/// Composition table, for looking up the keys of charmap entries for characters in decomposed
+/// form, such as `e` followed by U+0301, using binary search.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CompositionTable<'a> {
+    /// The characters in decomposed form, sorted.
+    pub decomposed: &'a [&'a str],
+    /// The key of the charmap entry for each character in composed form, in the same order.
+    pub keys: &'a [CharmapEntryKey<'a>],
 }
 
 impl<'a, C, const N: usize> CharmapEntry<'a, C, N>
@@ -52,6 +86,7 @@ where
         key: "",
         advance_chars: 0,
         advance_width_to: |_| 0.0,
+        advance_height: 0.0,
         glyph: Glyph::NULL,
     };
 }
@@ -62,15 +97,169 @@ where
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
     /// Finds the charmap entry for a given input, traversing the branch nodes while also matching
-    /// characters from the specified string slice until a leaf node is found.
+    /// characters from the specified string slice until a leaf node or a table node is found.
     pub fn get(&self, slice: &str) -> &CharmapEntry<'a, C, N> {
         let mut chars = slice.chars();
         let first = chars.next().unwrap_or_default();
-        let slice = chars.as_str();
 
         match self {
             Self::Leaf(entry) => entry,
-            Self::Branch(map) => map(first).get(slice),
+            Self::Branch(map) => map(first).get(chars.as_str()),
+            Self::Table(table) => table.get(slice),
         }
     }
 }
+
+impl<'a, C, const N: usize> CharmapTable<'a, C, N>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    /// Finds the charmap entry with the longest key that the specified string slice starts with,
+    /// or the charmap entry for `notdef` if there is none.
+    pub fn get(&self, slice: &str) -> &CharmapEntry<'a, C, N> {
+        let Some(first) = slice.chars().next() else {
+            return &self.notdef;
+        };
+
+        let start = self.chars.partition_point(|char| *char < first);
+        let end = start + self.chars[start..].partition_point(|char| *char == first);
+        let entries = self.entries[start..end].iter();
+        let entry = entries.rev().find(|entry| slice.starts_with(entry.key));
+
+        entry.unwrap_or(&self.notdef)
+    }
+}
+
+impl<'a> CompositionTable<'a> {
+    /// Composition table with no entries.
+    pub const EMPTY: Self = Self {
+        decomposed: &[],
+        keys: &[],
+    };
+
+    /// Finds the longest character in decomposed form that the specified string slice starts with,
+    /// returning it along with the key of the charmap entry for the character in composed form, or
+    /// `None` if there is none.
+    pub fn get(&self, slice: &str) -> Option<(&'a str, CharmapEntryKey<'a>)> {
+        let first = slice.chars().next()?;
+        let first_of = |decomposed: &&str| decomposed.chars().next().unwrap_or_default();
+        let start = self
+            .decomposed
+            .partition_point(|item| first_of(item) < first);
+        let end = start + self.decomposed[start..].partition_point(|item| first_of(item) == first);
+        let items = self.decomposed[start..end]
+            .iter()
+            .zip(&self.keys[start..end]);
+        let item = items
+            .rev()
+            .find(|(decomposed, _)| slice.starts_with(**decomposed));
+
+        item.map(|(decomposed, key)| (*decomposed, *key))
+    }
+}
+
+/// Charmap entry data, for accessing the glyph cluster of a charmap entry.
+///
+/// This trait is implemented for references to [`CharmapEntry`] and for the charmap entries of
+/// [`BitmapFontBlob`](crate::blob::BitmapFontBlob), which are read from a byte slice.
+pub trait CharmapEntryData<'a, C, const N: usize>: Copy
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    /// Returns the key for the charmap entry.
+    fn key(&self) -> CharmapEntryKey<'a>;
+
+    /// Returns the value for advancing the position in the text run for the charmap entry.
+    fn advance_chars(&self) -> usize;
+
+    /// Returns the value for advancing the position along the _x_-axis for the charmap entry in
+    /// order to get to the position of the charmap entry with the specified key.
+    fn advance_width_to(&self, key: CharmapEntryKey<'a>) -> f32;
+
+    /// Returns the value for advancing the position along the _y_-axis for the charmap entry in
+    /// vertical writing mode. The value is zero for bitmap fonts that have been rendered without
+    /// vertical metrics.
+    fn advance_height(&self) -> f32;
+
+    /// Returns the glyph identifier along with the _x_- and _y_-offsets for the glyph at the
+    /// specified index in the glyph cluster, or `None` if out of bounds. The offsets are zero for
+    /// the first glyph; for additional glyphs, these are relative to the next glyph cluster.
+    fn glyph(&self, index: usize) -> Option<(GlyphId, f32, f32)>;
+
+    /// Returns the image for the glyph at the specified index in the glyph cluster, using the
+    /// specified sub-pixel offset data, see [`ImageSet::get`](crate::image::ImageSet::get).
+    fn image(&self, index: usize, position: usize) -> Image<ImageData<'a, C>>;
+
+    /// Returns the image for the outline layer of the glyph at the specified index in the glyph
+    /// cluster, using the specified sub-pixel offset data. The image is empty for bitmap fonts that
+    /// have been rendered without outlines.
+    fn outline_image(&self, index: usize, position: usize) -> Image<ImageData<'a, C>>;
+}
+
+impl<'a, C, const N: usize> CharmapEntryData<'a, C, N> for &CharmapEntry<'a, C, N>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    fn key(&self) -> CharmapEntryKey<'a> {
+        self.key
+    }
+
+    fn advance_chars(&self) -> usize {
+        self.advance_chars
+    }
+
+    fn advance_width_to(&self, key: CharmapEntryKey<'a>) -> f32 {
+        (self.advance_width_to)(key)
+    }
+
+    fn advance_height(&self) -> f32 {
+        self.advance_height
+    }
+
+    fn glyph(&self, index: usize) -> Option<(GlyphId, f32, f32)> {
+        let Some(index) = index.checked_sub(1) else {
+            return Some((self.glyph.id, 0.0, 0.0));
+        };
+
+        let mut next = self.glyph.next?;
+        for _ in 0..index {
+            next = next.glyph.next?;
+        }
+
+        Some((next.glyph.id, next.x_offset, next.y_offset))
+    }
+
+    fn image(&self, index: usize, position: usize) -> Image<ImageData<'a, C>> {
+        match nth_glyph(&self.glyph, index) {
+            Some(glyph) => glyph.images.get(position).clone(),
+            None => Image::NULL,
+        }
+    }
+
+    fn outline_image(&self, index: usize, position: usize) -> Image<ImageData<'a, C>> {
+        match nth_glyph(&self.glyph, index) {
+            Some(glyph) => glyph.outline.get(position).clone(),
+            None => Image::NULL,
+        }
+    }
+}
+
+/// Returns the glyph at the specified index in the glyph cluster, or `None` if out of bounds.
+fn nth_glyph<'a, 'b, C, const N: usize>(
+    glyph: &'b Glyph<'a, C, N>,
+    index: usize,
+) -> Option<&'b Glyph<'a, C, N>>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    let mut glyph = glyph;
+    for _ in 0..index {
+        glyph = &glyph.next?.glyph;
+    }
+
+    Some(glyph)
+}
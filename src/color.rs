@@ -4,7 +4,8 @@
 //! [`BinaryColor`], [`Gray2`], [`Gray4`], or [`Gray8`]. This module provides the functions that
 //! enable downsampling, color conversion from any [`GrayColor`] to any other color type that a
 //! [`DrawTarget`](../../embedded_graphics_core/draw_target/trait.DrawTarget.html) expects,
-//! applying color settings, and mixing colors in [`Screen`] blend mode.
+//! applying color settings, mixing colors in [`Screen`] blend mode, and combining the color
+//! channels of three colors for sub-pixel rendering with [`Screen::subpixel`].
 
 use core::array;
 
@@ -44,6 +45,37 @@ pub trait Screen {
     /// Converts the first two color values to the range of colors that is defined by the specified
     /// start and end colors, mixing the intermediate colors and returning the final result.
     fn screen(self, other: Self, start: Self, end: Self) -> Self;
+
+    /// Returns the color that has the red, green, and blue channels of the specified colors, for
+    /// sub-pixel rendering. Colors without red, green, and blue channels take the second color,
+    /// which is what the provided implementation does.
+    fn subpixel(_: Self, green: Self, _: Self) -> Self
+    where
+        Self: Sized,
+    {
+        green
+    }
+}
+
+/// Alpha blending.
+///
+/// A color that implements this trait can be covered by another color of the same type with a
+/// given opacity, interpolating each color component linearly between the two colors.
+pub trait Blend {
+    /// Returns this color covered by the other color, with an opacity from `0` (transparent) to
+    /// `255` (opaque).
+    fn blend(self, other: Self, alpha: u8) -> Self;
+}
+
+/// Order of the sub-pixels from left to right, for bitmap fonts that have been rendered using
+/// sub-pixel anti-aliasing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SubpixelOrder {
+    /// Red, green, and blue sub-pixels, in this order.
+    Rgb,
+    /// Blue, green, and red sub-pixels, in this order.
+    Bgr,
 }
 
 impl<T: Copy, const N: usize> Colormap<T, N> {
@@ -101,6 +133,16 @@ const fn convert_channel<const N: usize>(value: u8, start: u8, end: u8) -> u8 {
     (result >> SHIFT) as u8
 }
 
+impl<const N: usize> Colormap<u8, N> {
+    /// Returns the colormap that maps each gray value to an opacity, from `0` for the first gray
+    /// value to `255` for the last one.
+    pub(crate) fn alpha() -> Self {
+        let alphas = array::from_fn(|index| convert_channel::<N>(index as u8, 0, 255));
+
+        Self(alphas)
+    }
+}
+
 macro_rules! impl_linear_rgb {
     ($($rgb_type:ty),+) => {
         $(
@@ -153,6 +195,46 @@ impl<const N: usize> Linear<BinaryColor> for Colormap<BinaryColor, N> {
     }
 }
 
+macro_rules! impl_blend_rgb {
+    ($($rgb_type:ty),+) => {
+        $(
+            impl Blend for $rgb_type {
+                fn blend(self, other: Self, alpha: u8) -> Self {
+                    let r = convert_channel::<256>(alpha, self.r(), other.r());
+                    let g = convert_channel::<256>(alpha, self.g(), other.g());
+                    let b = convert_channel::<256>(alpha, self.b(), other.b());
+
+                    Self::new(r, g, b)
+                }
+            }
+        )*
+    }
+}
+
+impl_blend_rgb!(
+    Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888
+);
+
+macro_rules! impl_blend_gray {
+    ($($gray_type:ty),+) => {
+        $(
+            impl Blend for $gray_type {
+                fn blend(self, other: Self, alpha: u8) -> Self {
+                    Self::new(convert_channel::<256>(alpha, self.luma(), other.luma()))
+                }
+            }
+        )*
+    }
+}
+
+impl_blend_gray!(Gray2, Gray4, Gray8);
+
+impl Blend for BinaryColor {
+    fn blend(self, other: Self, alpha: u8) -> Self {
+        if alpha < 128 { self } else { other }
+    }
+}
+
 macro_rules! impl_invert_rgb {
     ($($rgb_type:ty),+) => {
         $(
@@ -226,6 +308,10 @@ macro_rules! impl_screen_mix_rgb {
 
                     <$rgb_type>::new(r, g, b)
                 }
+
+                fn subpixel(red: Self, green: Self, blue: Self) -> Self {
+                    Self::new(red.r(), green.g(), blue.b())
+                }
             }
         )*
     }
@@ -365,4 +451,35 @@ mod tests {
         screen_mix_channel_0_0_on_128_128, 0, 0, 128, 128, 128,
         screen_mix_channel_0_0_on_0_0, 0, 0, 0, 0, 0,
     }
+
+    macro_rules! test_blend {
+        (
+            $(
+                $fn_ident:ident, $color:expr, $other:expr, $alpha:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let result = $color.blend($other, $alpha);
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_blend! {
+        blend_rgb888_0_on_black, Rgb888::BLACK, Rgb888::new(255, 128, 64), 0, Rgb888::BLACK,
+        blend_rgb888_128_on_black,
+            Rgb888::BLACK, Rgb888::new(255, 128, 64), 128, Rgb888::new(128, 64, 32),
+        blend_rgb888_255_on_black,
+            Rgb888::BLACK, Rgb888::new(255, 128, 64), 255, Rgb888::new(255, 128, 64),
+        blend_rgb888_64_on_white,
+            Rgb888::WHITE, Rgb888::BLACK, 64, Rgb888::new(191, 191, 191),
+        blend_rgb565_128_on_black,
+            Rgb565::BLACK, Rgb565::new(31, 63, 31), 128, Rgb565::new(16, 32, 16),
+        blend_gray4_85_on_gray4_3, Gray4::new(3), Gray4::new(15), 85, Gray4::new(7),
+        blend_binary_color_127_on_off, BinaryColor::Off, BinaryColor::On, 127, BinaryColor::Off,
+        blend_binary_color_128_on_off, BinaryColor::Off, BinaryColor::On, 128, BinaryColor::On,
+    }
 }
@@ -1,9 +1,12 @@
+use core::iter;
+
 use embedded_graphics::iterator::raw::RawDataSlice;
 use embedded_graphics::pixelcolor::PixelColor;
 use embedded_graphics::pixelcolor::raw::BigEndian;
 
 use crate::DecorationDimensions;
-use crate::charmap::{Charmap, CharmapEntry};
+use crate::charmap::{Charmap, CharmapEntry, CharmapEntryData, CompositionTable};
+use crate::color::SubpixelOrder;
 use crate::metrics::BitmapFontMetrics;
 
 /// Bitmap font.
@@ -16,12 +19,18 @@ where
 {
     /// The charmap that holds all glyph data.
     pub charmap: Charmap<'a, C, N>,
+    /// The composition table for the characters in composed form that the charmap has entries
+    /// for, which is used for normalizing text to NFC while looking up charmap entries.
+    pub compositions: CompositionTable<'a>,
     /// The metrics that are scaled to go with the bitmap font.
     pub metrics: BitmapFontMetrics,
     /// The dimensions of the underline decoration.
     pub underline: DecorationDimensions,
     /// The dimensions of the strikethrough decoration.
     pub strikethrough: DecorationDimensions,
+    /// The order of the sub-pixels that the glyph images have been rendered for, if rendered using
+    /// sub-pixel anti-aliasing.
+    pub subpixel: Option<SubpixelOrder>,
 }
 
 impl<'a, C, const N: usize> BitmapFont<'a, C, N>
@@ -32,8 +41,236 @@ where
     /// The invisible bitmap font.
     pub const NULL: Self = Self {
         charmap: Charmap::Leaf(CharmapEntry::NULL),
+        compositions: CompositionTable::EMPTY,
         metrics: BitmapFontMetrics::NULL,
         underline: DecorationDimensions::NULL,
         strikethrough: DecorationDimensions::NULL,
+        subpixel: None,
     };
 }
+
+/// Bitmap font data, for looking up charmap entries and for getting the metrics that are scaled to
+/// go with the bitmap font.
+///
+/// This trait is implemented for [`BitmapFont`], the data structure that [`mplus!`] expands to, and
+/// for [`BitmapFontBlob`], which reads the binary format that [`mplus_bytes!`] expands to.
+///
+/// [`mplus!`]: mplusfonts_macros::mplus
+/// [`mplus_bytes!`]: mplusfonts_macros::mplus_bytes
+/// [`BitmapFontBlob`]: crate::blob::BitmapFontBlob
+pub trait BitmapFontData<'a, C, const N: usize>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    /// The type of charmap entries in the bitmap font.
+    type Entry<'b>: CharmapEntryData<'a, C, N>
+    where
+        Self: 'b;
+
+    /// Finds the charmap entry for a given input, matching as many characters as possible from
+    /// the specified string slice.
+    fn get(&self, slice: &str) -> Self::Entry<'_>;
+
+    /// Finds the charmap entry for a given input as [`get`](Self::get) does, while also matching
+    /// characters in decomposed form, such as `e` followed by U+0301, to the charmap entry for the
+    /// character in composed form. Returns the charmap entry along with the number of characters
+    /// that it matches, which is what the position in the text run is advanced by.
+    ///
+    /// Only bitmap fonts that have a composition table match characters in decomposed form; for
+    /// others, the number of characters is the value from the charmap entry.
+    fn get_normalized(&self, slice: &str) -> (Self::Entry<'_>, usize) {
+        let entry = self.get(slice);
+
+        (entry, entry.advance_chars())
+    }
+
+    /// Returns `true` if the bitmap font has glyphs for all characters in the specified string
+    /// slice, that is, if none of them are drawn using the `.notdef` glyph.
+    fn covers(&self, text: &str) -> bool {
+        self.unsupported_chars(text).next().is_none()
+    }
+
+    /// Returns an iterator over the characters in the specified string slice that the bitmap font
+    /// has no glyphs for, along with their byte indices. These are drawn using the `.notdef` glyph,
+    /// which usually looks like an empty box.
+    fn unsupported_chars(&self, text: &str) -> impl Iterator<Item = (usize, char)> {
+        let mut index = 0;
+        iter::from_fn(move || {
+            loop {
+                let slice = &text[index..];
+                let first = slice.chars().next()?;
+                let (entry, count) = self.get_normalized(slice);
+                let start = index;
+                index += slice
+                    .char_indices()
+                    .nth(count.max(1))
+                    .map_or(slice.len(), |(index, _)| index);
+
+                if let Some((0, _, _)) = entry.glyph(0) {
+                    return Some((start, first));
+                }
+            }
+        })
+    }
+
+    /// Returns the metrics that are scaled to go with the bitmap font.
+    fn metrics(&self) -> BitmapFontMetrics;
+
+    /// Returns the dimensions of the underline decoration.
+    fn underline(&self) -> DecorationDimensions;
+
+    /// Returns the dimensions of the strikethrough decoration.
+    fn strikethrough(&self) -> DecorationDimensions;
+
+    /// Returns the order of the sub-pixels that the glyph images have been rendered for, if
+    /// rendered using sub-pixel anti-aliasing. Each pixel of such glyph images is then made up of
+    /// three consecutive gray values, one for each sub-pixel.
+    fn subpixel(&self) -> Option<SubpixelOrder>;
+}
+
+impl<'a, C, const N: usize> BitmapFontData<'a, C, N> for BitmapFont<'a, C, N>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    type Entry<'b>
+        = &'b CharmapEntry<'a, C, N>
+    where
+        Self: 'b;
+
+    fn get(&self, slice: &str) -> Self::Entry<'_> {
+        self.charmap.get(slice)
+    }
+
+    fn get_normalized(&self, slice: &str) -> (Self::Entry<'_>, usize) {
+        let entry = self.charmap.get(slice);
+        let Some((decomposed, key)) = self.compositions.get(slice) else {
+            return (entry, entry.advance_chars);
+        };
+
+        let count = decomposed.chars().count();
+        if entry.advance_chars >= count {
+            return (entry, entry.advance_chars);
+        }
+
+        (self.charmap.get(key), count)
+    }
+
+    fn metrics(&self) -> BitmapFontMetrics {
+        self.metrics
+    }
+
+    fn underline(&self) -> DecorationDimensions {
+        self.underline
+    }
+
+    fn strikethrough(&self) -> DecorationDimensions {
+        self.strikethrough
+    }
+
+    fn subpixel(&self) -> Option<SubpixelOrder> {
+        self.subpixel
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::mplus;
+
+    macro_rules! test_get_normalized {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $slice:expr, $expected_key:expr, $expected_count:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let (entry, count) = bitmap_font.get_normalized($slice);
+                    assert_eq!(entry.key(), $expected_key);
+                    assert_eq!(count, $expected_count);
+                }
+            )*
+        }
+    }
+
+    test_get_normalized! {
+        get_normalized_2_400_10_false_1_4_composed,
+            mplus!(font = 2, size = 10, sources = ["eéệ"]),
+            "éa", "é", 1,
+
+        get_normalized_2_400_10_false_1_4_decomposed,
+            mplus!(font = 2, size = 10, sources = ["eéệ"]),
+            "e\u{301}a", "é", 2,
+
+        get_normalized_2_400_10_false_1_4_decomposed_with_two_marks,
+            mplus!(font = 2, size = 10, sources = ["eéệ"]),
+            "e\u{323}\u{302}", "ệ", 3,
+
+        get_normalized_2_400_10_false_1_4_not_composed,
+            mplus!(font = 2, size = 10, sources = ["eéệ"]),
+            "e\u{300}", "e", 1,
+
+        get_normalized_2_400_10_false_1_4_table,
+            mplus!(font = 2, size = 10, charmap = table, sources = ["eéệ"]),
+            "e\u{301}", "é", 2,
+
+        get_normalized_2_400_10_false_1_4_decomposed_source,
+            mplus!(font = 2, size = 10, sources = ["e\u{301}"]),
+            "e\u{301}", "e\u{301}", 2,
+    }
+
+    macro_rules! test_unsupported_chars {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $text:expr, $expected_chars:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let unsupported_chars: Vec<_> = bitmap_font.unsupported_chars($text).collect();
+                    let expected_chars: Vec<(usize, char)> = Vec::from($expected_chars);
+                    assert_eq!(unsupported_chars, expected_chars);
+                    assert_eq!(bitmap_font.covers($text), expected_chars.is_empty());
+                }
+            )*
+        }
+    }
+
+    test_unsupported_chars! {
+        unsupported_chars_2_400_10_false_1_4_empty,
+            mplus!(font = 2, size = 10, sources = ["Hello"]),
+            "", [],
+
+        unsupported_chars_2_400_10_false_1_4_covered,
+            mplus!(font = 2, size = 10, sources = ["Hello"]),
+            "Hello", [],
+
+        unsupported_chars_2_400_10_false_1_4_not_in_sources,
+            mplus!(font = 2, size = 10, sources = ["Hello"]),
+            "Hello, world", [(5, ','), (6, ' '), (7, 'w'), (9, 'r'), (11, 'd')],
+
+        unsupported_chars_2_400_10_false_1_4_not_in_font,
+            mplus!(font = 2, size = 10, sources = ["aǖb"]),
+            "aǖb", [(1, 'ǖ')],
+
+        unsupported_chars_2_400_10_false_1_4_decomposed,
+            mplus!(font = 2, size = 10, sources = ["Café"]),
+            "Cafe\u{301}", [],
+
+        unsupported_chars_2_400_10_false_1_4_ligature,
+            mplus!(font = 2, size = 10, sources = [kern('a'..='z', ["ffi"])]),
+            "office!", [(6, '!')],
+
+        unsupported_chars_1_400_10_false_1_4_kanji,
+            mplus!(font = 1, size = 10, sources = ["日本"]),
+            "日本語", [(6, '語')],
+    }
+}
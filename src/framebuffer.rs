@@ -0,0 +1,58 @@
+//! Framebuffers with pixel read-back.
+//!
+//! Drawing text without filling in the background requires reading back the colors of the pixels
+//! that are already on the draw target, so that the anti-aliased pixels of glyphs can be blended
+//! with them. This module provides the [`ReadPixel`] trait for draw targets that keep their pixels
+//! in memory, which is implemented for [`Framebuffer`], [`MockDisplay`], and --- with the
+//! `simulator` feature enabled --- for `SimulatorDisplay`.
+
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::framebuffer::Framebuffer;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::image::GetPixel;
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::pixelcolor::PixelColor;
+
+/// Draw target with pixel read-back.
+///
+/// A draw target that implements this trait can return the color of any of its pixels, as it was
+/// last drawn.
+pub trait ReadPixel: DrawTarget {
+    /// Returns the color of the pixel at the specified point, or `None` if the point is outside
+    /// the draw target or if the pixel has not been drawn.
+    fn read_pixel(&self, point: Point) -> Option<Self::Color>;
+}
+
+impl<C, R, BO, const WIDTH: usize, const HEIGHT: usize, const N: usize> ReadPixel
+    for Framebuffer<C, R, BO, WIDTH, HEIGHT, N>
+where
+    C: PixelColor,
+    Self: DrawTarget<Color = C> + GetPixel<Color = C>,
+{
+    fn read_pixel(&self, point: Point) -> Option<Self::Color> {
+        self.pixel(point)
+    }
+}
+
+impl<C: PixelColor> ReadPixel for MockDisplay<C> {
+    fn read_pixel(&self, point: Point) -> Option<Self::Color> {
+        let (0..64, 0..64) = (point.x, point.y) else {
+            return None;
+        };
+
+        self.get_pixel(point)
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl<C: PixelColor> ReadPixel for embedded_graphics_simulator::SimulatorDisplay<C> {
+    fn read_pixel(&self, point: Point) -> Option<Self::Color> {
+        use embedded_graphics::geometry::Dimensions;
+
+        if !self.bounding_box().contains(point) {
+            return None;
+        }
+
+        Some(self.get_pixel(point))
+    }
+}
@@ -27,6 +27,9 @@ where
     pub id: GlyphId,
     /// The image set.
     pub images: ImageSet<'a, C, N>,
+    /// The image set for the outline layer, which is empty for bitmap fonts that have been
+    /// rendered without outlines.
+    pub outline: ImageSet<'a, C, N>,
     /// The next glyph.
     pub next: Option<&'a NextGlyph<'a, C, N>>,
 }
@@ -56,6 +59,7 @@ where
     pub const NULL: Self = Self {
         id: 0,
         images: ImageSet::Repeated(Image::NULL),
+        outline: ImageSet::Repeated(Image::NULL),
         next: None,
     };
 }
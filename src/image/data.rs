@@ -0,0 +1,81 @@
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{OriginDimensions, Size};
+use embedded_graphics::image::ImageDrawable;
+use embedded_graphics::iterator::raw::RawDataSlice;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::pixelcolor::raw::BigEndian;
+use embedded_graphics::primitives::Rectangle;
+
+use crate::image::{Colors, ImageRaw, ImageRle};
+
+/// Image drawable with a reference to glyph image data, which is either stored as is or
+/// run-length encoded, depending on the `compress` setting of the bitmap font.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ImageData<'a, C>
+where
+    C: PixelColor + From<C::Raw>,
+{
+    /// Uncompressed data.
+    Raw(ImageRaw<'a, C>),
+    /// Run-length encoded data.
+    Rle(ImageRle<'a, C>),
+}
+
+impl<C> OriginDimensions for ImageData<'_, C>
+where
+    C: PixelColor + From<C::Raw>,
+{
+    fn size(&self) -> Size {
+        match self {
+            Self::Raw(image_raw) => image_raw.size(),
+            Self::Rle(image_rle) => image_rle.size(),
+        }
+    }
+}
+
+impl<'a, C> ImageDrawable for ImageData<'a, C>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self {
+            Self::Raw(image_raw) => image_raw.draw(target),
+            Self::Rle(image_rle) => image_rle.draw(target),
+        }
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self {
+            Self::Raw(image_raw) => image_raw.draw_sub_image(target, area),
+            Self::Rle(image_rle) => image_rle.draw_sub_image(target, area),
+        }
+    }
+}
+
+impl<'a, C> Colors<C> for ImageData<'a, C>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    fn colors(&self) -> impl IntoIterator<Item = C> {
+        let (raw_colors, rle_colors) = match self {
+            Self::Raw(image_raw) => (Some(image_raw.colors().into_iter()), None),
+            Self::Rle(image_rle) => (None, Some(image_rle.colors().into_iter())),
+        };
+
+        raw_colors
+            .into_iter()
+            .flatten()
+            .chain(rle_colors.into_iter().flatten())
+    }
+}
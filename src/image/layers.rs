@@ -0,0 +1,258 @@
+use core::array;
+
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Dimensions, OriginDimensions, Point, Size};
+use embedded_graphics::image::ImageDrawable;
+use embedded_graphics::pixelcolor::{BinaryColor, Gray2, Gray4, Gray8, PixelColor};
+use embedded_graphics::primitives::{PointsIter, Rectangle};
+use embedded_graphics::transform::Transform;
+
+use crate::color::{Colormap, Screen};
+use crate::image::{Colors, Image, ImageData, ImageDrawableExt, SubImage};
+
+/// Image drawable with three layers of gray values, such as a shadow layer and an outline layer
+/// under a fill layer, and a colormap for each layer.
+///
+/// While also performing color conversion, drawing this image drawable involves mapping the gray
+/// values of the bottom layer to colors between the background and the color of that layer, over
+/// which the gray values of the middle layer and then those of the top layer are blended in
+/// [`Screen`] blend mode.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ImageLayers<'a, U, T, const N: usize>
+where
+    U: ImageDrawable,
+    T: PixelColor + Screen,
+{
+    layers: [Image<U>; 3],
+    size: Size,
+    colormaps: [&'a Colormap<T, N>; 3],
+}
+
+impl<'a, U, T, const N: usize> ImageLayers<'a, U, T, N>
+where
+    U: ImageDrawable,
+    T: PixelColor + Screen,
+{
+    /// Creates a new image with an image drawable that covers all of the specified images, which
+    /// are the layers from bottom to top.
+    fn new_image(layers: [&Image<U>; 3], colormaps: [&'a Colormap<T, N>; 3]) -> Image<Self>
+    where
+        U: Clone,
+        Self: ImageDrawable,
+    {
+        let area = layers
+            .iter()
+            .rev()
+            .map(|layer| layer.bounding_box())
+            .reduce(|area, layer_box| envelope(&area, &layer_box))
+            .unwrap_or_default();
+
+        let offset = Point::zero() - area.top_left;
+        let layers =
+            layers.map(|layer| Image::new(layer.image_drawable.clone(), layer.offset + offset));
+
+        let image_drawable = Self {
+            layers,
+            size: area.size,
+            colormaps,
+        };
+
+        Image::new(image_drawable, area.top_left)
+    }
+}
+
+impl<U, T, const N: usize> ImageLayers<'_, U, T, N>
+where
+    U: ImageDrawable,
+    T: PixelColor + Screen,
+{
+    /// Returns an image drawable with the same colormaps, having all of its layers clipped to the
+    /// specified area.
+    fn clipped(&self, area: &Rectangle) -> ImageLayers<'_, SubImage<'_, U>, T, N> {
+        let layers = self.layers.each_ref().map(|layer| {
+            let mut layer = layer.clipped(area);
+            layer.offset -= area.top_left;
+
+            layer
+        });
+
+        ImageLayers {
+            layers,
+            size: area.size,
+            colormaps: self.colormaps,
+        }
+    }
+
+    /// Returns the color of a pixel, given the colors that its gray values in each layer are
+    /// mapped to, from bottom to top.
+    fn compose(&self, colors: [T; 3]) -> T {
+        let [bottom, layers @ ..] = colors;
+        let colormaps = &self.colormaps[1..];
+        let mut result = bottom;
+        for (color, colormap) in layers.into_iter().zip(colormaps) {
+            let start = colormap.first();
+            if color != start {
+                result = color.screen(result, start, colormap.last());
+            }
+        }
+
+        result
+    }
+}
+
+impl<U, T, const N: usize> OriginDimensions for ImageLayers<'_, U, T, N>
+where
+    U: ImageDrawable,
+    T: PixelColor + Screen,
+{
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+macro_rules! impl_image_drawable {
+    (
+        $(
+            $color_type:ty, $array_length:literal,
+        )*
+    ) => {
+        $(
+            impl<'a> Image<ImageData<'a, $color_type>> {
+                /// Returns an image that covers this image and the specified images, having an
+                /// image drawable that draws the specified images as the bottom and the middle
+                /// layers under this image, using the specified colormaps for each layer, from
+                /// bottom to top.
+                ///
+                /// The colormap for the bottom layer is expected to start with the background
+                /// color; the others, with the inverse of their last color, so that each layer can
+                /// be blended over the layers under it.
+                pub fn layered<'b, T>(
+                    &self,
+                    layers: [&Self; 2],
+                    colormaps: [&'b Colormap<T, $array_length>; 3],
+                ) -> Image<ImageLayers<'b, ImageData<'a, $color_type>, T, $array_length>>
+                where
+                    T: PixelColor + Screen,
+                {
+                    let [bottom, middle] = layers;
+
+                    ImageLayers::new_image([bottom, middle, self], colormaps)
+                }
+            }
+
+            impl<U, T> ImageLayers<'_, U, T, $array_length>
+            where
+                U: ImageDrawable<Color = $color_type> + Colors<$color_type>,
+                T: PixelColor + Screen,
+            {
+                /// Returns an iterator that yields the colors that the gray values in each layer
+                /// are mapped to, from bottom to top, one array for each pixel.
+                fn layers(&self) -> impl Iterator<Item = [T; 3]> {
+                    let layer_boxes = self.layers.each_ref().map(|layer| layer.bounding_box());
+                    let mut layers = self.layers.each_ref().map(|layer| layer.colors().into_iter());
+                    let area = Rectangle::new(Point::zero(), self.size);
+
+                    area.points().map(move |point| {
+                        array::from_fn(|index| {
+                            let colormap = self.colormaps[index];
+
+                            layer_boxes[index]
+                                .contains(point)
+                                .then(|| layers[index].next())
+                                .flatten()
+                                .map_or(colormap.first(), |color| colormap.get(color))
+                        })
+                    })
+                }
+            }
+
+            impl<U, T> Image<ImageLayers<'_, U, T, $array_length>>
+            where
+                U: ImageDrawable<Color = $color_type> + Colors<$color_type>,
+                T: PixelColor + Screen,
+            {
+                /// Draws the intersection of this image with the specified image, mixing the
+                /// colors of each layer in [`Screen`] blend mode before composing the layers.
+                pub fn draw_mixed<D>(&self, other: &Self, target: &mut D) -> Result<(), D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    let area = self.bounding_box().intersection(&other.bounding_box());
+                    let first_area = area.translate(Point::zero() - self.offset);
+                    let second_area = area.translate(Point::zero() - other.offset);
+                    let first = self.image_drawable.clipped(&first_area);
+                    let second = other.image_drawable.clipped(&second_area);
+                    let colors = first.layers().zip(second.layers()).map(|(colors, other)| {
+                        let colors = array::from_fn(|index| {
+                            let colormap = first.colormaps[index];
+
+                            colors[index].screen(other[index], colormap.first(), colormap.last())
+                        });
+
+                        first.compose(colors)
+                    });
+
+                    target.fill_contiguous(&area, colors)
+                }
+            }
+
+            impl<U, T> ImageDrawable for ImageLayers<'_, U, T, $array_length>
+            where
+                U: ImageDrawable<Color = $color_type> + Colors<$color_type>,
+                T: PixelColor + Screen,
+            {
+                type Color = T;
+
+                fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+                where
+                    D: DrawTarget<Color = Self::Color>,
+                {
+                    let area = Rectangle::new(Point::zero(), self.size());
+
+                    target.fill_contiguous(&area, self.colors())
+                }
+
+                fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+                where
+                    D: DrawTarget<Color = Self::Color>,
+                {
+                    let sub_image = self.sub_image(area);
+                    let area = Rectangle::new(Point::zero(), sub_image.size());
+
+                    target.fill_contiguous(&area, sub_image.colors())
+                }
+            }
+
+            impl<U, T> Colors<T> for ImageLayers<'_, U, T, $array_length>
+            where
+                U: ImageDrawable<Color = $color_type> + Colors<$color_type>,
+                T: PixelColor + Screen,
+            {
+                fn colors(&self) -> impl IntoIterator<Item = T> {
+                    self.layers().map(|colors| self.compose(colors))
+                }
+            }
+        )*
+    }
+}
+
+impl_image_drawable! {
+    BinaryColor, 2,
+    Gray2, 4,
+    Gray4, 16,
+    Gray8, 256,
+}
+
+/// Returns the smallest rectangle that contains both of the specified rectangles, ignoring either
+/// one if it is zero-sized.
+fn envelope(first: &Rectangle, second: &Rectangle) -> Rectangle {
+    match (first.bottom_right(), second.bottom_right()) {
+        (Some(first_corner), Some(second_corner)) => Rectangle::with_corners(
+            first.top_left.component_min(second.top_left),
+            first_corner.component_max(second_corner),
+        ),
+        (_, None) => *first,
+        (None, Some(_)) => *second,
+    }
+}
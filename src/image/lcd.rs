@@ -0,0 +1,130 @@
+use core::iter;
+
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{OriginDimensions, Point, Size};
+use embedded_graphics::image::ImageDrawable;
+use embedded_graphics::pixelcolor::{BinaryColor, Gray2, Gray4, Gray8, PixelColor};
+use embedded_graphics::primitives::Rectangle;
+
+use crate::color::{Colormap, Screen, SubpixelOrder};
+use crate::image::{Colors, Image, ImageData, ImageDrawableExt};
+
+/// Image drawable with a reference to glyph image data, in which each pixel is made up of three
+/// consecutive gray values, one for each sub-pixel, and a colormap.
+///
+/// While also performing color conversion, drawing this image drawable involves taking the red,
+/// green, and blue channels of a pixel from the colors that the gray values of its sub-pixels are
+/// mapped to, in the specified order of the sub-pixels.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ImageLcd<'a, 'b, C, T, const N: usize>
+where
+    C: PixelColor + From<C::Raw>,
+    T: PixelColor + Screen,
+{
+    image_data: ImageData<'a, C>,
+    order: SubpixelOrder,
+    colormap: &'b Colormap<T, N>,
+}
+
+impl<C, T, const N: usize> OriginDimensions for ImageLcd<'_, '_, C, T, N>
+where
+    C: PixelColor + From<C::Raw>,
+    T: PixelColor + Screen,
+{
+    fn size(&self) -> Size {
+        let Size { width, height } = self.image_data.size();
+
+        Size::new(width / 3, height)
+    }
+}
+
+macro_rules! impl_image_drawable {
+    (
+        $(
+            $color_type:ty, $array_length:literal,
+        )*
+    ) => {
+        $(
+            impl<'a> Image<ImageData<'a, $color_type>> {
+                /// Returns an image with the same offset, having an image drawable that maps the
+                /// gray values of each three consecutive sub-pixels to a single pixel, using the
+                /// specified order of the sub-pixels and colormap.
+                pub fn subpixel_mapped<'b, T>(
+                    &self,
+                    order: SubpixelOrder,
+                    colormap: &'b Colormap<T, $array_length>,
+                ) -> Image<ImageLcd<'a, 'b, $color_type, T, $array_length>>
+                where
+                    T: PixelColor + Screen,
+                {
+                    let image_drawable = ImageLcd {
+                        image_data: self.image_drawable.clone(),
+                        order,
+                        colormap,
+                    };
+
+                    Image::new(image_drawable, self.offset)
+                }
+            }
+
+            impl<T> ImageDrawable for ImageLcd<'_, '_, $color_type, T, $array_length>
+            where
+                T: PixelColor + Screen,
+            {
+                type Color = T;
+
+                fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+                where
+                    D: DrawTarget<Color = Self::Color>,
+                {
+                    let area = Rectangle::new(Point::zero(), self.size());
+
+                    target.fill_contiguous(&area, self.colors())
+                }
+
+                fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+                where
+                    D: DrawTarget<Color = Self::Color>,
+                {
+                    let sub_image = self.sub_image(area);
+                    let area = Rectangle::new(Point::zero(), sub_image.size());
+
+                    target.fill_contiguous(&area, sub_image.colors())
+                }
+            }
+
+            impl<T> Colors<T> for ImageLcd<'_, '_, $color_type, T, $array_length>
+            where
+                T: PixelColor + Screen,
+            {
+                fn colors(&self) -> impl IntoIterator<Item = T> {
+                    let mut colors = self
+                        .image_data
+                        .colors()
+                        .into_iter()
+                        .map(|color| self.colormap.get(color));
+
+                    iter::from_fn(move || {
+                        let left = colors.next()?;
+                        let middle = colors.next()?;
+                        let right = colors.next()?;
+                        let color = match self.order {
+                            SubpixelOrder::Rgb => T::subpixel(left, middle, right),
+                            SubpixelOrder::Bgr => T::subpixel(right, middle, left),
+                        };
+
+                        Some(color)
+                    })
+                }
+            }
+        )*
+    }
+}
+
+impl_image_drawable! {
+    BinaryColor, 2,
+    Gray2, 4,
+    Gray4, 16,
+    Gray8, 256,
+}
@@ -5,8 +5,12 @@
 //! image drawable is owned by the image, some of the image drawables in this module expose the
 //! contiguous stream of pixel colors, allowing for data manipulation on the image level rather
 //! than at the draw target only.
+mod data;
+mod layers;
+mod lcd;
 mod mix;
 mod raw;
+mod rle;
 mod set;
 mod sub;
 
@@ -20,13 +24,17 @@ use embedded_graphics::pixelcolor::raw::BigEndian;
 use embedded_graphics::primitives::Rectangle;
 use embedded_graphics::transform::Transform;
 
+pub use data::ImageData;
+pub use layers::ImageLayers;
+pub use lcd::ImageLcd;
 pub use mix::{ImageMix, Mixed};
 pub use raw::ImageRaw;
+pub use rle::ImageRle;
 pub use set::ImageSet;
 pub use sub::{ImageDrawableExt, SubImage};
 
 /// Image that owns the image drawable and holds image offset data.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Image<T: ImageDrawable> {
     image_drawable: T,
@@ -39,14 +47,14 @@ pub trait Colors<T> {
     fn colors(&self) -> impl IntoIterator<Item = T>;
 }
 
-impl<'a, C> Image<ImageRaw<'a, C>>
+impl<'a, C> Image<ImageData<'a, C>>
 where
     C: PixelColor + From<C::Raw>,
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
     /// The zero-sized, zero-offset image.
     pub const NULL: Self = Self {
-        image_drawable: ImageRaw::new(&[], 0),
+        image_drawable: ImageData::Raw(ImageRaw::new(&[], 0)),
         offset: Point::zero(),
     };
 }
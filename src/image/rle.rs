@@ -0,0 +1,345 @@
+use core::iter;
+use core::marker::PhantomData;
+
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Dimensions, OriginDimensions, Point, Size};
+use embedded_graphics::image::ImageDrawable;
+use embedded_graphics::iterator::raw::RawDataSlice;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::pixelcolor::raw::{BigEndian, RawData};
+use embedded_graphics::primitives::Rectangle;
+
+use crate::image::Colors;
+
+/// Image drawable with a reference to run-length encoded data interpreted as colors having type
+/// `C`, which are decoded while drawing.
+///
+/// The data starts with the height of the image as a little-endian `u16`, followed by runs of
+/// pixels in row-major order, without padding at the end of each row. Each run starts with a
+/// control byte:
+///
+/// - `0b00nnnnnn` --- a run of _n_ + 1 pixels having the value zero,
+/// - `0b01nnnnnn` --- a run of _n_ + 1 pixels having the value that is in the next byte,
+/// - `0b1nnnnnnn` --- a run of _n_ + 1 pixels having the values that are in the next bytes.
+///
+/// Pixel values are packed in the most significant bits first, the same as in [`ImageRaw`], and
+/// for a run of literal values, the last byte is padded with zeros.
+///
+/// [`ImageRaw`]: crate::image::ImageRaw
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ImageRle<'a, C>
+where
+    C: PixelColor + From<C::Raw>,
+{
+    data: &'a [u8],
+    size: Size,
+    phantom: PhantomData<&'a C>,
+}
+
+/// Iterator over the raw values of the pixels in run-length encoded data.
+struct RawValues<'a, R>
+where
+    RawDataSlice<'a, R, BigEndian>: IntoIterator<Item = R>,
+{
+    data: &'a [u8],
+    value: &'a [u8],
+    values: Option<<RawDataSlice<'a, R, BigEndian> as IntoIterator>::IntoIter>,
+    count: usize,
+}
+
+impl<'a, C> ImageRle<'a, C>
+where
+    C: PixelColor + From<C::Raw>,
+{
+    /// Creates a new image drawable with the specified run-length encoded data and image width.
+    pub const fn new(data: &'a [u8], width: u32) -> Self {
+        let [low, high, ref data @ ..] = *data else {
+            return Self {
+                data: &[],
+                size: Size::zero(),
+                phantom: PhantomData,
+            };
+        };
+
+        let height = u16::from_le_bytes([low, high]) as u32;
+
+        Self {
+            data,
+            size: Size::new(width, height),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, C> ImageRle<'a, C>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    /// Returns an iterator over the colors of the pixels in the specified area, in row-major
+    /// order. The area must be contained in the bounding box of the image drawable.
+    pub(crate) fn colors_in(&self, area: Rectangle) -> impl Iterator<Item = C> + use<'a, C> {
+        let width = self.size.width as usize;
+        let left = area.top_left.x as usize;
+        let top = area.top_left.y as usize;
+        let area_width = area.size.width as usize;
+        let mut values = RawValues::new(self.data);
+        values.skip_values(top * width + left);
+
+        let mut remaining = area_width * area.size.height as usize;
+        let mut x = 0;
+        iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+
+            if x == area_width {
+                values.skip_values(width - area_width);
+                x = 0;
+            }
+
+            x += 1;
+            remaining -= 1;
+            values.next().map(Into::into)
+        })
+    }
+}
+
+impl<'a, R> RawValues<'a, R>
+where
+    R: RawData,
+    RawDataSlice<'a, R, BigEndian>: IntoIterator<Item = R>,
+{
+    /// Creates a new iterator with the specified run-length encoded data, without the height.
+    const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            value: &[],
+            values: None,
+            count: 0,
+        }
+    }
+
+    /// Advances to the next run, returning `None` if there are no more runs.
+    fn next_run(&mut self) -> Option<()> {
+        let (&control, data) = self.data.split_first()?;
+        let (count, data) = match control >> 6 {
+            0b00 => {
+                self.value = &[0];
+                self.values = None;
+
+                ((control & 0b0011_1111) as usize + 1, data)
+            }
+            0b01 => {
+                let (value, data) = data.split_at_checked(1)?;
+                self.value = value;
+                self.values = None;
+
+                ((control & 0b0011_1111) as usize + 1, data)
+            }
+            _ => {
+                let count = (control & 0b0111_1111) as usize + 1;
+                let length = (count * R::BITS_PER_PIXEL).div_ceil(8);
+                let (values, data) = data.split_at_checked(length)?;
+                self.value = &[];
+                self.values = Some(RawDataSlice::new(values).into_iter());
+
+                (count, data)
+            }
+        };
+
+        self.count = count;
+        self.data = data;
+
+        Some(())
+    }
+
+    /// Skips the specified number of values, stepping over whole runs where possible.
+    fn skip_values(&mut self, mut n: usize) {
+        while n > 0 {
+            if self.count == 0 && self.next_run().is_none() {
+                return;
+            }
+
+            let skipped = n.min(self.count);
+            if let Some(values) = self.values.as_mut() {
+                values.nth(skipped - 1);
+            }
+
+            self.count -= skipped;
+            n -= skipped;
+        }
+    }
+}
+
+impl<'a, R> Iterator for RawValues<'a, R>
+where
+    R: RawData,
+    RawDataSlice<'a, R, BigEndian>: IntoIterator<Item = R>,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count == 0 {
+            self.next_run()?;
+        }
+
+        self.count -= 1;
+        match self.values.as_mut() {
+            Some(values) => values.next(),
+            None => RawDataSlice::new(self.value).into_iter().next(),
+        }
+    }
+}
+
+impl<C> OriginDimensions for ImageRle<'_, C>
+where
+    C: PixelColor + From<C::Raw>,
+{
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<'a, C> ImageDrawable for ImageRle<'a, C>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let area = Rectangle::new(Point::zero(), self.size);
+
+        target.fill_contiguous(&area, self.colors_in(area))
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        if area.is_zero_sized() || self.bounding_box().intersection(area) != *area {
+            return Ok(());
+        }
+
+        let colors = self.colors_in(*area);
+
+        target.fill_contiguous(&Rectangle::new(Point::zero(), area.size), colors)
+    }
+}
+
+impl<'a, C> Colors<C> for ImageRle<'a, C>
+where
+    C: PixelColor + From<C::Raw>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    fn colors(&self) -> impl IntoIterator<Item = C> {
+        self.colors_in(Rectangle::new(Point::zero(), self.size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::Drawable;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Gray4;
+
+    use super::*;
+    use crate::image::{Image, ImageData, ImageRaw};
+
+    const RAW_DATA: [u8; 9] = [0x00, 0x00, 0x00, 0xFF, 0xFF, 0x30, 0x12, 0x30, 0x00];
+    const RLE_DATA: [u8; 9] = [0x03, 0x00, 0x04, 0x43, 0xF0, 0x83, 0x31, 0x23, 0x01];
+
+    macro_rules! test_draw_sub_image {
+        (
+            $(
+                $fn_ident:ident, $area:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let image: ImageRaw<Gray4> = ImageRaw::new(&RAW_DATA, 5);
+                    let mut expected = MockDisplay::new();
+                    image.draw_sub_image(&mut expected, &$area).unwrap();
+
+                    let image: ImageRle<Gray4> = ImageRle::new(&RLE_DATA, 5);
+                    let mut display = MockDisplay::new();
+                    image.draw_sub_image(&mut display, &$area).unwrap();
+                    assert_eq!(display, expected);
+
+                    let image: ImageData<Gray4> = ImageData::Rle(ImageRle::new(&RLE_DATA, 5));
+                    let mut display = MockDisplay::new();
+                    image.draw_sub_image(&mut display, &$area).unwrap();
+                    assert_eq!(display, expected);
+                }
+            )*
+        }
+    }
+
+    test_draw_sub_image! {
+        draw_sub_image_0_0_5_3,
+            Rectangle::new(Point::new(0, 0), Size::new(5, 3)),
+        draw_sub_image_1_1_3_2,
+            Rectangle::new(Point::new(1, 1), Size::new(3, 2)),
+        draw_sub_image_3_2_4_4,
+            Rectangle::new(Point::new(3, 2), Size::new(4, 4)),
+        draw_sub_image_4_0_1_3,
+            Rectangle::new(Point::new(4, 0), Size::new(1, 3)),
+        draw_sub_image_5_3_1_1,
+            Rectangle::new(Point::new(5, 3), Size::new(1, 1)),
+    }
+
+    macro_rules! test_draw_clipped {
+        (
+            $(
+                $fn_ident:ident, $offset:expr, $area:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let image: ImageRaw<Gray4> = ImageRaw::new(&RAW_DATA, 5);
+                    let mut expected = MockDisplay::new();
+                    Image::new(image, $offset).clipped(&$area).draw(&mut expected).unwrap();
+
+                    let image: ImageRle<Gray4> = ImageRle::new(&RLE_DATA, 5);
+                    let mut display = MockDisplay::new();
+                    Image::new(image, $offset).clipped(&$area).draw(&mut display).unwrap();
+                    assert_eq!(display, expected);
+                }
+            )*
+        }
+    }
+
+    test_draw_clipped! {
+        draw_clipped_at_10_20_to_12_20_2_8,
+            Point::new(10, 20),
+            Rectangle::new(Point::new(12, 20), Size::new(2, 8)),
+        draw_clipped_at_0_0_to_1_2_9_9,
+            Point::new(0, 0),
+            Rectangle::new(Point::new(1, 2), Size::new(9, 9)),
+    }
+
+    #[test]
+    fn draw_and_colors_of_image_rle() {
+        let image: ImageRaw<Gray4> = ImageRaw::new(&RAW_DATA, 5);
+        let mut expected = MockDisplay::new();
+        image.draw(&mut expected).unwrap();
+
+        let result: ImageRle<Gray4> = ImageRle::new(&RLE_DATA, 5);
+        let mut display = MockDisplay::new();
+        result.draw(&mut display).unwrap();
+        assert_eq!(result.size(), image.size());
+        assert_eq!(display, expected);
+        assert!(result.colors().into_iter().eq(image.colors()));
+
+        let result: ImageData<Gray4> = ImageData::Rle(ImageRle::new(&RLE_DATA, 5));
+        assert_eq!(result.size(), image.size());
+        assert!(result.colors().into_iter().eq(image.colors()));
+    }
+}
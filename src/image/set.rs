@@ -2,7 +2,7 @@ use embedded_graphics::iterator::raw::RawDataSlice;
 use embedded_graphics::pixelcolor::PixelColor;
 use embedded_graphics::pixelcolor::raw::BigEndian;
 
-use crate::image::{Image, ImageRaw};
+use crate::image::{Image, ImageData};
 
 /// Image set, for looking up glyph images using sub-pixel offset data.
 ///
@@ -19,10 +19,10 @@ where
     C: PixelColor + From<C::Raw>,
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
-    /// A single repeated image referencing raw data.
-    Repeated(Image<ImageRaw<'a, C>>),
-    /// An array of images referencing raw data.
-    Array([Image<ImageRaw<'a, C>>; N]),
+    /// A single repeated image referencing glyph image data.
+    Repeated(Image<ImageData<'a, C>>),
+    /// An array of images referencing glyph image data.
+    Array([Image<ImageData<'a, C>>; N]),
 }
 
 impl<'a, C, const N: usize> ImageSet<'a, C, N>
@@ -32,7 +32,7 @@ where
 {
     /// Returns either the single repeated image or the image at the specified index, wrapping if
     /// greater than or equal to the length of the array.
-    pub const fn get(&self, index: usize) -> &Image<ImageRaw<'a, C>> {
+    pub const fn get(&self, index: usize) -> &Image<ImageData<'a, C>> {
         match self {
             Self::Repeated(image) => image,
             Self::Array(array) => &array[index % N],
@@ -0,0 +1,295 @@
+//! Paragraph layout.
+//!
+//! Text that does not fit on a single line is broken into lines that are no wider than a given
+//! width. Lines are broken after spaces, which do not count toward the width of a line, and between
+//! characters of the CJK scripts, which have no spaces between words. Line breaks that would place
+//! closing brackets, punctuation marks such as `、` and `。`, small kana, or the prolonged sound mark
+//! `ー` at the start of a line, or opening brackets at the end of a line, are avoided by moving the
+//! character before them to the next line as well, following the Japanese line-breaking rules
+//! (_kinsoku shori_). A word that is wider than the line is broken between any two glyph clusters.
+//!
+//! Each line feed ends a paragraph. The lines are laid out with [`Justification`], see
+//! [`draw_paragraph`](crate::style::BitmapFontStyle::draw_paragraph). Nothing is allocated.
+//!
+//! Text that is to be kept on a single line can be truncated instead, replacing the characters
+//! that do not fit with an ellipsis at the end, at the start, or in the middle, see [`Truncation`]
+//! and [`draw_truncated`](crate::style::BitmapFontStyle::draw_truncated). Text is only cut between
+//! glyph clusters, and never before a combining mark.
+
+use core::iter;
+
+/// Justification of the lines of a paragraph.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Justification {
+    /// Lines start at the left edge.
+    #[default]
+    Left,
+    /// Lines are centered between the left and the right edges.
+    Center,
+    /// Lines end at the right edge.
+    Right,
+    /// Lines start at the left edge and end at the right edge, except for the last line of each
+    /// paragraph, which starts at the left edge. The extra space is distributed between words that
+    /// are separated by spaces or, for lines that have no spaces, between glyph clusters.
+    Full,
+}
+
+/// Truncation of text that does not fit within a width.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Truncation {
+    /// Characters are cut off at the end.
+    #[default]
+    End,
+    /// Characters are cut off at the start.
+    Start,
+    /// Characters are cut off in the middle, keeping both the start and the end.
+    Middle,
+}
+
+/// Line of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Line<'s> {
+    /// The text of the line, without trailing spaces and line feeds.
+    pub text: &'s str,
+    /// The advance width of the text of the line in pixels.
+    pub width: u32,
+    /// Whether the line is the last line of its paragraph.
+    pub ends_paragraph: bool,
+}
+
+/// Characters that a line does not start with.
+const NO_START: &str = concat!(
+    ",.!?:;)]}%",
+    "、。，．・：；？！‐゠–〜～",
+    "）」』】〕〉》〙〗｝］｠〟’”»",
+    "ーヽヾゝゞ々〻",
+    "ぁぃぅぇぉっゃゅょゎゕゖ",
+    "ァィゥェォッャュョヮヵヶㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿ",
+);
+
+/// Characters that a line does not end with.
+const NO_END: &str = "([{（「『【〔〈《〘〖｛［｟〝‘“«";
+
+/// Returns `true` if the specified character belongs to the CJK scripts or symbols, or is a
+/// fullwidth or halfwidth form.
+fn is_cjk(char: char) -> bool {
+    matches!(
+        char,
+        '\u{1100}'..='\u{11FF}'
+            | '\u{2E80}'..='\u{9FFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FE30}'..='\u{FE4F}'
+            | '\u{FF00}'..='\u{FFEF}'
+            | '\u{20000}'..='\u{3FFFF}'
+    )
+}
+
+/// Returns `true` if the specified character extends the grapheme cluster before it, such as a
+/// combining mark, a variation selector, or a zero-width joiner.
+pub(crate) fn is_grapheme_extend(char: char) -> bool {
+    matches!(
+        char,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{200D}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{3099}'..='\u{309A}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
+/// Returns `true` if a line can be broken between the specified characters.
+fn can_break(previous: char, next: char) -> bool {
+    if next == ' ' {
+        return false;
+    }
+
+    if previous == ' ' {
+        return true;
+    }
+
+    if NO_START.contains(next) || NO_END.contains(previous) {
+        return false;
+    }
+
+    is_cjk(previous) || is_cjk(next)
+}
+
+/// Breaks the specified text into lines that are no wider than the specified width, using the
+/// specified function to get the caret positions in a paragraph, starting at `0.0`.
+pub(crate) fn lines<'s, I>(
+    text: &'s str,
+    width: u32,
+    mut carets: impl FnMut(&'s str) -> I,
+) -> impl Iterator<Item = Line<'s>>
+where
+    I: Iterator<Item = (usize, f32)>,
+{
+    let width = width as f32;
+    let mut rest = text;
+    iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let (paragraph, next_paragraph) = rest.split_once('\n').unwrap_or((rest, ""));
+        let mut ink_end = (0, 0.0);
+        let mut line_break = None;
+        let mut previous = (0, 0.0);
+        for (index, x) in carets(paragraph).skip(1) {
+            let (previous_index, _) = previous;
+            let cluster = &paragraph[previous_index..index];
+            let is_space = cluster.starts_with(' ');
+            let first = cluster.chars().next().unwrap_or_default();
+            let last = paragraph[..previous_index].chars().next_back();
+            if last.is_some_and(|last| can_break(last, first)) && ink_end.0 > 0 {
+                line_break = Some((ink_end, previous_index));
+            }
+
+            if !is_space && x > width {
+                let ((end, end_x), next) = match line_break {
+                    Some(line_break) => line_break,
+                    None if previous_index > 0 => (ink_end, previous_index),
+                    None => ((index, x), index),
+                };
+                let next = next.max(end);
+                let ends_paragraph = paragraph[next..].trim_start_matches(' ').is_empty();
+                rest = if ends_paragraph {
+                    next_paragraph
+                } else {
+                    &rest[next..]
+                };
+
+                let text = &paragraph[..end];
+                let width = end_x as u32;
+                let line = Line {
+                    text,
+                    width,
+                    ends_paragraph,
+                };
+
+                return Some(line);
+            }
+
+            if !is_space {
+                ink_end = (index, x);
+            }
+
+            previous = (index, x);
+        }
+
+        rest = next_paragraph;
+
+        let (end, end_x) = ink_end;
+        let text = &paragraph[..end];
+        let width = end_x as u32;
+        let line = Line {
+            text,
+            width,
+            ends_paragraph: true,
+        };
+
+        Some(line)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn carets(text: &str) -> impl Iterator<Item = (usize, f32)> {
+        let carets = text.char_indices().scan(0.0, |x, (index, char)| {
+            *x += if is_cjk(char) { 2.0 } else { 1.0 };
+
+            Some((index + char.len_utf8(), *x))
+        });
+
+        iter::once((0, 0.0)).chain(carets)
+    }
+
+    macro_rules! test_lines {
+        (
+            $(
+                $fn_ident:ident, $text:expr, $width:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let mut lines = lines($text, $width, carets);
+                    for (text, width, ends_paragraph) in $expected {
+                        let expected = Line { text, width, ends_paragraph };
+                        assert_eq!(lines.next(), Some(expected));
+                    }
+
+                    assert_eq!(lines.next(), None);
+                }
+            )*
+        }
+    }
+
+    test_lines! {
+        lines_empty,
+            "", 10,
+            [],
+
+        lines_fit,
+            "hello world", 20,
+            [("hello world", 11, true)],
+
+        lines_break_after_space,
+            "hello world", 8,
+            [("hello", 5, false), ("world", 5, true)],
+
+        lines_break_after_spaces_that_do_not_fit,
+            "hello    world", 5,
+            [("hello", 5, false), ("world", 5, true)],
+
+        lines_break_long_word,
+            "abcdefgh", 3,
+            [("abc", 3, false), ("def", 3, false), ("gh", 2, true)],
+
+        lines_break_narrow,
+            "ab", 0,
+            [("a", 1, false), ("b", 1, true)],
+
+        lines_break_between_kanji,
+            "日本語の文章", 7,
+            [("日本語", 6, false), ("の文章", 6, true)],
+
+        lines_no_start_with_period,
+            "日本語。文章", 6,
+            [("日本", 4, false), ("語。文", 6, false), ("章", 2, true)],
+
+        lines_no_start_with_small_kana,
+            "これはちょっと", 10,
+            [("これは", 6, false), ("ちょっと", 8, true)],
+
+        lines_no_start_with_prolonged_sound_mark,
+            "アイデータ", 6,
+            [("アイ", 4, false), ("データ", 6, true)],
+
+        lines_no_end_with_opening_bracket,
+            "本の「題名」", 6,
+            [("本の", 4, false), ("「題", 4, false), ("名」", 4, true)],
+
+        lines_no_start_with_closing_bracket,
+            "「題名」です", 6,
+            [("「題", 4, false), ("名」で", 6, false), ("す", 2, true)],
+
+        lines_break_between_kanji_and_latin,
+            "日本English", 8,
+            [("日本", 4, false), ("English", 7, true)],
+
+        lines_paragraphs,
+            "ab\n\ncd ef\n", 3,
+            [("ab", 2, true), ("", 0, true), ("cd", 2, false), ("ef", 2, true)],
+    }
+}
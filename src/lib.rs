@@ -82,6 +82,11 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_copy_implementations)]
 
+#[cfg(test)]
+extern crate self as mplusfonts;
+#[cfg(test)]
+extern crate std;
+
 mod adapter;
 mod builder;
 mod charmap;
@@ -89,15 +94,19 @@ mod font;
 mod metrics;
 mod rect;
 
+pub mod blob;
 pub mod color;
+pub mod framebuffer;
 pub mod glyph;
 pub mod image;
+pub mod layout;
 pub mod style;
 
-pub use font::BitmapFont;
+pub use font::{BitmapFont, BitmapFontData};
 
 pub use charmap::*;
 pub use metrics::*;
 
 pub use mplusfonts_macros::mplus;
+pub use mplusfonts_macros::mplus_bytes;
 pub use mplusfonts_macros::strings;
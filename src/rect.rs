@@ -1,6 +1,8 @@
 use embedded_graphics::geometry::{Point, Size};
 use embedded_graphics::primitives::Rectangle;
 
+use crate::style::Rotation;
+
 /// Extension trait for rectangles.
 pub trait RectangleExt {
     /// Returns the left half of the rectangle, rounding down the resulting width.
@@ -29,6 +31,33 @@ pub trait RectangleExt {
     /// Returns the rectangle with its left side indented to the right, making the specified column
     /// its new left side.
     fn indent_to(&self, right: i32) -> Self;
+
+    /// Returns the smallest rectangle that covers both the rectangle and the specified area,
+    /// ignoring either one if it has no pixels.
+    fn envelope(&self, other: &Self) -> Self;
+
+    /// Returns the rectangle rotated about the specified origin, covering the pixels that the
+    /// pixels of the rectangle are rotated to.
+    fn rotate_about(&self, origin: Point, rotation: Rotation) -> Self;
+
+    /// Returns the rectangle scaled up about the specified origin, covering the blocks of pixels
+    /// that the pixels of the rectangle are scaled to.
+    fn scale_about(&self, origin: Point, scale: u32) -> Self;
+
+    /// Returns the rectangle scaled down about the specified origin, covering the pixels whose
+    /// blocks of pixels overlap the rectangle.
+    fn scale_down_about(&self, origin: Point, scale: u32) -> Self;
+}
+
+/// Extension trait for points.
+pub trait PointExt {
+    /// Returns the point scaled up about the specified origin, which is the top-left corner of the
+    /// block of pixels that the pixel at the point is scaled to.
+    fn scale_about(&self, origin: Point, scale: u32) -> Self;
+
+    /// Returns the point scaled down about the specified origin, which is the pixel whose block of
+    /// pixels contains the point.
+    fn scale_down_about(&self, origin: Point, scale: u32) -> Self;
 }
 
 impl RectangleExt for Rectangle {
@@ -120,6 +149,73 @@ impl RectangleExt for Rectangle {
 
         Self { top_left, size }
     }
+
+    fn envelope(&self, other: &Self) -> Self {
+        let (Some(bottom_right), Some(other_bottom_right)) =
+            (self.bottom_right(), other.bottom_right())
+        else {
+            return if self.is_zero_sized() { *other } else { *self };
+        };
+
+        let top_left = self.top_left.component_min(other.top_left);
+        let bottom_right = bottom_right.component_max(other_bottom_right);
+
+        Self::with_corners(top_left, bottom_right)
+    }
+
+    fn rotate_about(&self, origin: Point, rotation: Rotation) -> Self {
+        let size = match rotation {
+            Rotation::Deg0 | Rotation::Deg180 => self.size,
+            Rotation::Deg90 | Rotation::Deg270 => Size::new(self.size.height, self.size.width),
+        };
+        let top_left = rotation.rotate_about(self.top_left, origin);
+        let Some(bottom_right) = self.bottom_right() else {
+            return Self { top_left, size };
+        };
+
+        let bottom_right = rotation.rotate_about(bottom_right, origin);
+        let top_left = top_left.component_min(bottom_right);
+
+        Self { top_left, size }
+    }
+
+    fn scale_about(&self, origin: Point, scale: u32) -> Self {
+        let top_left = self.top_left.scale_about(origin, scale);
+        let width = self.size.width.saturating_mul(scale);
+        let height = self.size.height.saturating_mul(scale);
+        let size = Size::new(width, height);
+
+        Self { top_left, size }
+    }
+
+    fn scale_down_about(&self, origin: Point, scale: u32) -> Self {
+        let top_left = self.top_left.scale_down_about(origin, scale);
+        let Some(bottom_right) = self.bottom_right() else {
+            return Self::new(top_left, Size::zero());
+        };
+
+        let bottom_right = bottom_right.scale_down_about(origin, scale);
+
+        Self::with_corners(top_left, bottom_right)
+    }
+}
+
+impl PointExt for Point {
+    fn scale_about(&self, origin: Point, scale: u32) -> Self {
+        let scale = scale.try_into().unwrap_or(i32::MAX);
+        let x = self.x.saturating_sub(origin.x).saturating_mul(scale);
+        let y = self.y.saturating_sub(origin.y).saturating_mul(scale);
+
+        Point::new(origin.x.saturating_add(x), origin.y.saturating_add(y))
+    }
+
+    fn scale_down_about(&self, origin: Point, scale: u32) -> Self {
+        let scale = scale.try_into().unwrap_or(i32::MAX).max(1);
+        let x = self.x.saturating_sub(origin.x).div_euclid(scale);
+        let y = self.y.saturating_sub(origin.y).div_euclid(scale);
+
+        Point::new(origin.x.saturating_add(x), origin.y.saturating_add(y))
+    }
 }
 
 #[cfg(test)]
@@ -560,4 +656,185 @@ mod tests {
             -1,
             Rectangle::new(Point::new(i32::MAX, i32::MAX), Size::new(u32::MAX, u32::MAX)),
     }
+    macro_rules! test_envelope {
+        (
+            $(
+                $fn_ident:ident, $self:expr, $other:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let result = $self.envelope(&$other);
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_envelope! {
+        envelope_10_20_30_40_and_50_0_10_10,
+            Rectangle::new(Point::new(10, 20), Size::new(30, 40)),
+            Rectangle::new(Point::new(50, 0), Size::new(10, 10)),
+            Rectangle::new(Point::new(10, 0), Size::new(50, 60)),
+
+        envelope_10_20_30_40_and_15_25_5_5,
+            Rectangle::new(Point::new(10, 20), Size::new(30, 40)),
+            Rectangle::new(Point::new(15, 25), Size::new(5, 5)),
+            Rectangle::new(Point::new(10, 20), Size::new(30, 40)),
+
+        envelope_10_20_30_40_and_0_0_0_0,
+            Rectangle::new(Point::new(10, 20), Size::new(30, 40)),
+            Rectangle::new(Point::new(0, 0), Size::new(0, 0)),
+            Rectangle::new(Point::new(10, 20), Size::new(30, 40)),
+
+        envelope_0_0_0_5_and_10_20_30_40,
+            Rectangle::new(Point::new(0, 0), Size::new(0, 5)),
+            Rectangle::new(Point::new(10, 20), Size::new(30, 40)),
+            Rectangle::new(Point::new(10, 20), Size::new(30, 40)),
+    }
+
+    macro_rules! test_rotate_about {
+        (
+            $(
+                $fn_ident:ident, $self:expr, $origin:expr, $rotation:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let result = $self.rotate_about($origin, $rotation);
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_rotate_about! {
+        rotate_about_100_200_by_0_for_110_220_30_40,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+            Point::new(100, 200),
+            Rotation::Deg0,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+
+        rotate_about_100_200_by_90_for_110_220_30_40,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+            Point::new(100, 200),
+            Rotation::Deg90,
+            Rectangle::new(Point::new(100 - 59, 210), Size::new(40, 30)),
+
+        rotate_about_100_200_by_180_for_110_220_30_40,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+            Point::new(100, 200),
+            Rotation::Deg180,
+            Rectangle::new(Point::new(100 - 39, 200 - 59), Size::new(30, 40)),
+
+        rotate_about_100_200_by_270_for_110_220_30_40,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+            Point::new(100, 200),
+            Rotation::Deg270,
+            Rectangle::new(Point::new(120, 200 - 39), Size::new(40, 30)),
+
+        rotate_about_0_0_by_90_for_1_2_0_0,
+            Rectangle::new(Point::new(1, 2), Size::new(0, 0)),
+            Point::new(0, 0),
+            Rotation::Deg90,
+            Rectangle::new(Point::new(-2, 1), Size::new(0, 0)),
+
+        rotate_about_minus_5_minus_5_by_270_for_0_0_3_1,
+            Rectangle::new(Point::new(0, 0), Size::new(3, 1)),
+            Point::new(-5, -5),
+            Rotation::Deg270,
+            Rectangle::new(Point::new(0, -5 - 7), Size::new(1, 3)),
+    }
+
+    macro_rules! test_scale_about {
+        (
+            $(
+                $fn_ident:ident, $self:expr, $origin:expr, $scale:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let result = $self.scale_about($origin, $scale);
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_scale_about! {
+        scale_about_100_200_by_1_for_110_220_30_40,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+            Point::new(100, 200),
+            1,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+
+        scale_about_100_200_by_3_for_110_220_30_40,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+            Point::new(100, 200),
+            3,
+            Rectangle::new(Point::new(130, 260), Size::new(90, 120)),
+
+        scale_about_100_200_by_2_for_90_190_5_5,
+            Rectangle::new(Point::new(90, 190), Size::new(5, 5)),
+            Point::new(100, 200),
+            2,
+            Rectangle::new(Point::new(80, 180), Size::new(10, 10)),
+
+        scale_about_0_0_by_4_for_1_2_0_0,
+            Rectangle::new(Point::new(1, 2), Size::new(0, 0)),
+            Point::new(0, 0),
+            4,
+            Rectangle::new(Point::new(4, 8), Size::new(0, 0)),
+    }
+
+    macro_rules! test_scale_down_about {
+        (
+            $(
+                $fn_ident:ident, $self:expr, $origin:expr, $scale:expr, $expected:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let result = $self.scale_down_about($origin, $scale);
+                    assert_eq!(result, $expected);
+                }
+            )*
+        }
+    }
+
+    test_scale_down_about! {
+        scale_down_about_100_200_by_1_for_110_220_30_40,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+            Point::new(100, 200),
+            1,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+
+        scale_down_about_100_200_by_3_for_130_260_90_120,
+            Rectangle::new(Point::new(130, 260), Size::new(90, 120)),
+            Point::new(100, 200),
+            3,
+            Rectangle::new(Point::new(110, 220), Size::new(30, 40)),
+
+        scale_down_about_100_200_by_2_for_99_199_3_3,
+            Rectangle::new(Point::new(99, 199), Size::new(3, 3)),
+            Point::new(100, 200),
+            2,
+            Rectangle::new(Point::new(99, 199), Size::new(2, 2)),
+
+        scale_down_about_10_10_by_4_for_0_0_64_64,
+            Rectangle::new(Point::new(0, 0), Size::new(64, 64)),
+            Point::new(10, 10),
+            4,
+            Rectangle::new(Point::new(7, 7), Size::new(17, 17)),
+
+        scale_down_about_0_0_by_4_for_5_6_0_0,
+            Rectangle::new(Point::new(5, 6), Size::new(0, 0)),
+            Point::new(0, 0),
+            4,
+            Rectangle::new(Point::new(1, 1), Size::new(0, 0)),
+    }
 }
@@ -7,43 +7,103 @@
 //! so, there is a trade-off:
 //!
 //! <div class="warning">
-//!   This crate does not support background transparency. If no background color is specified, it
-//!   defaults to black; this color is filled in from top to bottom, for the length of the text run.
+//!   The text renderer does not support background transparency. If no background color is
+//!   specified, it defaults to black; this color is filled in from top to bottom, for the length of
+//!   the text run. To draw text over images or gradients, use
+//!   <code>BitmapFontStyle::draw_string_transparent</code> instead.
 //! </div>
+//!
+//! [`draw_string_transparent`](BitmapFontStyle::draw_string_transparent) skips the background and
+//! blends the glyphs over the pixels that are already on a draw target that implements
+//! [`ReadPixel`], using the gray value of each pixel as its opacity.
+//!
+//! Bitmap fonts that have been rendered using sub-pixel anti-aliasing, with the `subpixel` argument
+//! of `mplus!`, are drawn by blending each color channel separately, using [`Screen::subpixel`].
+//!
+//! Bitmap fonts that have been rendered with outlines, with the `outline` argument of `mplus!`, are
+//! drawn with the outline layer under the glyphs when an outline color is set. A drop shadow, which
+//! is the coverage of each glyph drawn again at an offset, can be drawn under both of these layers
+//! when a shadow color is set. The layers are composed using
+//! [`ImageLayers`](crate::image::ImageLayers). Neither of them is drawn for bitmap fonts that have
+//! been rendered using sub-pixel anti-aliasing.
+//!
+//! Text can be rotated by 90, 180, or 270 degrees about its position, which rotates the glyphs
+//! together with the background, the decorations, and the direction in which the text advances.
+//! See [`Rotation`].
+//!
+//! Text can also be drawn in vertical writing mode with
+//! [`draw_string_vertical`](BitmapFontStyle::draw_string_vertical), which stacks glyphs from top
+//! to bottom. Bitmap fonts that have been rendered with the `vertical` argument of `mplus!` have
+//! vertical alternates for punctuation and vertical advances for each glyph cluster.
+//!
+//! The advance widths of glyph clusters can be changed with letter spacing and word spacing, which
+//! add extra space after each glyph cluster and after each space character, respectively, and the
+//! line height can be set to a value other than that of the bitmap font. Both measuring and drawing
+//! strings and whitespace take these settings into account.
+//!
+//! Text can be scaled up by an integer factor, which draws each pixel of a smaller bitmap font as
+//! a square block of pixels, so that large text, such as the digits of a clock, does not need a
+//! bitmap font that has been rendered at that size.
+//!
+//! For text fields and touch input, [`caret_positions`](BitmapFontStyle::caret_positions) returns
+//! the positions between glyph clusters, [`hit_test`](BitmapFontStyle::hit_test) finds the one
+//! that is the closest to a point, and [`measure_bounds`](BitmapFontStyle::measure_bounds) returns
+//! both the advance bounds and the ink bounds of a string.
+//!
+//! Paragraphs of text can be drawn with
+//! [`draw_paragraph`](BitmapFontStyle::draw_paragraph), which breaks them into lines that fit
+//! within a width, following the Japanese line-breaking rules, and justifies each line. See the
+//! [`layout`] module.
+//!
+//! A string that needs to fit within a width on a single line, such as a label or a file name, can
+//! be drawn with [`draw_truncated`](BitmapFontStyle::draw_truncated), which replaces the characters
+//! that do not fit with an [`ellipsis`](BitmapFontStyle::ellipsis).
 
 use core::iter;
+use core::marker::PhantomData;
 
 use embedded_graphics::Drawable;
-use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt as _};
 use embedded_graphics::geometry::{Dimensions, Point, Size};
+use embedded_graphics::image::ImageDrawable;
 use embedded_graphics::iterator::raw::RawDataSlice;
 use embedded_graphics::pixelcolor::raw::BigEndian;
 use embedded_graphics::pixelcolor::{BinaryColor, Gray2, Gray4, Gray8, PixelColor};
 use embedded_graphics::primitives::{PrimitiveStyle, Rectangle, StyledDrawable};
 use embedded_graphics::text::renderer::{CharacterStyle, TextMetrics, TextRenderer};
 use embedded_graphics::text::{Baseline, DecorationColor};
+use embedded_graphics::transform::Transform;
 
 use crate::adapter::DrawTargetExt;
-use crate::charmap::{Charmap, CharmapEntry};
-use crate::color::{Colormap, Invert, Linear, Screen};
-use crate::font::BitmapFont;
-use crate::glyph::NextGlyph;
-use crate::image::{Image, ImageRaw, Mixed};
-use crate::rect::RectangleExt;
+use crate::charmap::CharmapEntryData;
+use crate::color::{Blend, Colormap, Invert, Linear, Screen, SubpixelOrder};
+use crate::font::{BitmapFont, BitmapFontData};
+use crate::framebuffer::ReadPixel;
+use crate::image::{Colors, Image, ImageData, Mixed};
+use crate::layout::{self, Justification, Line, Truncation};
+use crate::rect::{PointExt, RectangleExt};
 
 pub use crate::builder::BitmapFontStyleBuilder;
 
+/// Maximum number of pixels that are blended before being drawn at once, by
+/// [`draw_string_transparent`](BitmapFontStyle::draw_string_transparent).
+const BLEND_BUFFER_LENGTH: usize = 32;
+
 /// Style using a bitmap font.
-#[derive(Debug, Clone)]
+///
+/// The bitmap font is either a [`BitmapFont`] or any other type that implements [`BitmapFontData`],
+/// such as [`BitmapFontBlob`](crate::blob::BitmapFontBlob).
+#[derive(Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct BitmapFontStyle<'a, 'b, T, C, const N: usize>
+pub struct BitmapFontStyle<'a, 'b, T, C, const N: usize, F = BitmapFont<'a, C, N>>
 where
     C: PixelColor + From<C::Raw>,
     T: PixelColor + Default + Invert + Screen,
+    F: BitmapFontData<'a, C, N>,
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
     /// The bitmap font.
-    pub font: &'b BitmapFont<'a, C, N>,
+    pub font: &'b F,
     /// The text color.
     pub text_color: Option<T>,
     /// The background color.
@@ -52,20 +112,226 @@ where
     pub underline_color: DecorationColor<T>,
     /// The strikethrough color.
     pub strikethrough_color: DecorationColor<T>,
+    /// The outline color.
+    pub outline_color: Option<T>,
+    /// The shadow color.
+    pub shadow_color: Option<T>,
+    /// The offset of the shadow, relative to the glyphs.
+    pub shadow_offset: Point,
+    /// The rotation of the text about its position.
+    pub rotation: Rotation,
+    /// The maximum number of digits that are drawn side by side, upright, in vertical writing
+    /// mode.
+    pub tate_chu_yoko: u8,
+    /// The characters that are drawn centered in cells of the same width.
+    pub tabular_chars: &'b str,
+    /// The width of the cells that the tabular characters are drawn in, which the builder sets to
+    /// the greatest advance width among them.
+    pub tabular_width: f32,
+    /// The extra space after each glyph cluster, in pixels.
+    pub letter_spacing: f32,
+    /// The extra space after each space character, in pixels.
+    pub word_spacing: f32,
+    /// The line height in pixels, overriding the line height of the bitmap font.
+    pub line_height: Option<u32>,
+    /// The factor by which the text is scaled up, drawing each pixel as a square block of pixels.
+    pub scale: u32,
+    /// The suffix that is drawn in place of the characters that are cut off from truncated text.
+    pub ellipsis: &'static str,
+    pub(crate) phantom: PhantomData<&'a C>,
+}
+
+/// Rotation of text, clockwise in steps of 90 degrees.
+///
+/// Text that is rotated by 90 or 270 degrees runs from top to bottom or from bottom to top,
+/// respectively, and text that is rotated by 180 degrees is upside down.
+///
+/// Each text run is rotated about its own position, so this does not change the direction in which
+/// lines of multi-line text are laid out.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Rotation {
+    /// No rotation.
+    #[default]
+    Deg0,
+    /// Rotation by 90 degrees.
+    Deg90,
+    /// Rotation by 180 degrees.
+    Deg180,
+    /// Rotation by 270 degrees.
+    Deg270,
+}
+
+impl Rotation {
+    /// Returns the rotation that undoes this rotation.
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Deg0 => Self::Deg0,
+            Self::Deg90 => Self::Deg270,
+            Self::Deg180 => Self::Deg180,
+            Self::Deg270 => Self::Deg90,
+        }
+    }
+
+    /// Returns the specified point, rotated about the specified origin.
+    pub const fn rotate_about(self, point: Point, origin: Point) -> Point {
+        let x = point.x.saturating_sub(origin.x);
+        let y = point.y.saturating_sub(origin.y);
+        let (x, y) = match self {
+            Self::Deg0 => (x, y),
+            Self::Deg90 => (y.saturating_neg(), x),
+            Self::Deg180 => (x.saturating_neg(), y.saturating_neg()),
+            Self::Deg270 => (y, x.saturating_neg()),
+        };
+
+        Point::new(origin.x.saturating_add(x), origin.y.saturating_add(y))
+    }
+}
+
+/// Bounds of a text run.
+///
+/// The advance bounds are the area of the line strip that the text advances through, from its
+/// position to the position of the next text run, and the ink bounds are the area that the pixels
+/// of its glyphs cover, which can extend past the advance bounds, or fall short of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TextBounds {
+    /// The advance bounds.
+    pub advance_bounds: Rectangle,
+    /// The ink bounds, which have no pixels if no glyph has any.
+    pub ink_bounds: Rectangle,
 }
 
-impl<'a, 'b, T, C, const N: usize> BitmapFontStyle<'a, 'b, T, C, N>
+impl<'a, 'b, T, C, const N: usize, F> BitmapFontStyle<'a, 'b, T, C, N, F>
 where
     C: PixelColor + From<C::Raw>,
     T: PixelColor + Default + Invert + Screen,
+    F: BitmapFontData<'a, C, N>,
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
     /// Creates a new style with the specified bitmap font and text color.
-    pub const fn new(font: &'b BitmapFont<'a, C, N>, text_color: T) -> Self {
+    pub const fn new(font: &'b F, text_color: T) -> Self {
         BitmapFontStyleBuilder::<'_, '_, _, BinaryColor, 0>::new()
             .text_color(text_color)
             .font(font)
-            .build()
+            .style
+    }
+
+    /// Returns the caret positions in the specified text, one at each boundary between glyph
+    /// clusters, along with the byte index of the boundary, from the start to the end of the text.
+    /// The first caret position is the position of the text, and the last one is the position of
+    /// the next text run, not counting the shadow.
+    ///
+    /// A glyph cluster that spans multiple characters, such as a ligature, has no caret positions
+    /// between its characters.
+    pub fn caret_positions(
+        &self,
+        text: &str,
+        position: Point,
+    ) -> impl Iterator<Item = (usize, Point)> {
+        let carets = carets_of_chars(self.font, text, position.x as f32, self.spacing());
+
+        carets.map(move |(index, x)| {
+            let point = Point::new(x as i32, position.y);
+
+            (index, self.transform_about(point, position))
+        })
+    }
+
+    /// Returns the byte index of the caret position in the specified text that is the closest to
+    /// the specified point along the direction in which the text advances, see
+    /// [`caret_positions`](Self::caret_positions).
+    pub fn hit_test(&self, text: &str, position: Point, point: Point) -> usize {
+        let Point { x, .. } = self.rotation.inverse().rotate_about(point, position);
+        let carets = carets_of_chars(self.font, text, position.x as f32, self.spacing());
+        let carets = carets.map(|(index, caret_x)| {
+            let caret = Point::new(caret_x as i32, position.y);
+            let caret = caret.scale_about(position, self.scale());
+
+            (index, caret.x.abs_diff(x))
+        });
+
+        carets
+            .min_by_key(|(_, distance)| *distance)
+            .map_or(0, |(index, _)| index)
+    }
+
+    /// Measures the advance bounds and the ink bounds of a string, see [`TextBounds`]. The ink
+    /// bounds include the outline and the shadow when these are drawn.
+    pub fn measure_bounds(&self, text: &str, position: Point, baseline: Baseline) -> TextBounds {
+        let mut x = position.x as f32;
+        let metrics = self.font.metrics();
+        let y = position.y.saturating_add(metrics.y_offset(baseline));
+        let top = y.saturating_sub(metrics.y_offset(Baseline::Top));
+        let bottom = y.saturating_sub(metrics.y_offset(Baseline::Bottom));
+        let (top, bottom) = self.y_extend_to_line_height(top, bottom);
+        let images = images_of_chars(self.font, text, &mut x, y as f32, self.spacing());
+        let subpixels = if self.font.subpixel().is_some() { 3 } else { 1 };
+        let is_outlined = self.outline_color.is_some() && subpixels == 1;
+        let shadow_offset = self.shadow_offset();
+        let mut ink_bounds = Rectangle::new(position, Size::zero());
+        for (image, outline, _) in images {
+            let outline = is_outlined.then_some(outline);
+            for image in iter::once(image).chain(outline) {
+                let mut image_box = image.bounding_box();
+                image_box.size.width /= subpixels;
+                let shadow_box = shadow_offset.map(|offset| image_box.translate(offset));
+                for image_box in iter::once(image_box).chain(shadow_box) {
+                    ink_bounds = ink_bounds.envelope(&image_box);
+                }
+            }
+        }
+
+        let width = (x as i32).saturating_sub(position.x);
+        let width = width.try_into().unwrap_or_default();
+        let height = bottom.saturating_sub(top).try_into().unwrap_or_default();
+        let advance_bounds = Rectangle {
+            top_left: Point::new(position.x, top),
+            size: Size::new(width, height),
+        };
+
+        let [advance_bounds, ink_bounds] = [advance_bounds, ink_bounds].map(|bounds| {
+            let bounds = bounds.scale_about(position, self.scale());
+
+            bounds.rotate_about(position, self.rotation)
+        });
+
+        TextBounds {
+            advance_bounds,
+            ink_bounds,
+        }
+    }
+
+    /// Breaks a string into lines that are no wider than the specified width, see the
+    /// [`layout`] module.
+    pub fn lines<'s>(&self, text: &'s str, width: u32) -> impl Iterator<Item = Line<'s>> {
+        let scale = self.scale();
+        let spacing = self.spacing();
+        let lines = layout::lines(text, width / scale, move |paragraph| {
+            carets_of_chars(self.font, paragraph, 0.0, spacing)
+        });
+
+        lines.map(move |line| Line {
+            width: line.width.saturating_mul(scale),
+            ..line
+        })
+    }
+
+    /// Measures a paragraph, see [`draw_paragraph`](Self::draw_paragraph). The bounding box is
+    /// the area that has the specified width and the height of the lines.
+    pub fn measure_paragraph(&self, text: &str, position: Point, width: u32) -> TextMetrics {
+        let count = self.lines(text, width).count();
+        let line_height = self.unscaled_line_height().saturating_mul(self.scale());
+        let height = line_height.saturating_mul(count.try_into().unwrap_or(u32::MAX));
+        let bounding_box = Rectangle::new(position, Size::new(width, height));
+        let bounding_box = bounding_box.rotate_about(position, self.rotation);
+        let next_position = Point::new(position.x, position.y.saturating_add_unsigned(height));
+        let next_position = self.rotation.rotate_about(next_position, position);
+
+        TextMetrics {
+            bounding_box,
+            next_position,
+        }
     }
 
     /// Returns the text color, falling back to the inverse of the default value for type `T` when
@@ -99,12 +365,260 @@ where
             DecorationColor::Custom(color) => Some(color),
         }
     }
+
+    /// Returns the optional shadow offset, which is set to a value when the shadow color is, unless
+    /// the bitmap font has been rendered using sub-pixel anti-aliasing.
+    fn shadow_offset(&self) -> Option<Point> {
+        match (self.font.subpixel(), self.shadow_color) {
+            (None, Some(_)) => Some(self.shadow_offset),
+            _ => None,
+        }
+    }
+
+    /// Returns the scale, falling back to one when set to zero.
+    fn scale(&self) -> u32 {
+        self.scale.max(1)
+    }
+
+    /// Returns the specified point, scaled up and then rotated about the specified origin, which
+    /// is the position of the text.
+    fn transform_about(&self, point: Point, origin: Point) -> Point {
+        let point = point.scale_about(origin, self.scale());
+
+        self.rotation.rotate_about(point, origin)
+    }
+
+    /// Returns the line height in pixels, without scaling.
+    fn unscaled_line_height(&self) -> u32 {
+        let Point { y, .. } = self.shadow_offset().unwrap_or_default();
+        let line_height = self.font.metrics().line_height();
+        let line_height = line_height.saturating_add(y.unsigned_abs());
+
+        self.line_height.unwrap_or(line_height)
+    }
+
+    /// Returns the specified left and right _x_-coordinates, moved apart so that the area between
+    /// them also covers the shadow.
+    fn x_extend_to_shadow(&self, left: i32, right: i32) -> (i32, i32) {
+        let Point { x, .. } = self.shadow_offset().unwrap_or_default();
+        let left = left.saturating_add(x.min(0));
+        let right = right.saturating_add(x.max(0));
+
+        (left, right)
+    }
+
+    /// Returns the specified top and bottom _y_-coordinates, moved apart so that the area between
+    /// them also covers the shadow.
+    fn y_extend_to_shadow(&self, top: i32, bottom: i32) -> (i32, i32) {
+        let Point { y, .. } = self.shadow_offset().unwrap_or_default();
+        let top = top.saturating_add(y.min(0));
+        let bottom = bottom.saturating_add(y.max(0));
+
+        (top, bottom)
+    }
+
+    /// Returns the specified top and bottom _y_-coordinates, moved so that the area between them
+    /// has the line height when it is set to a value, with the difference split evenly between the
+    /// top and the bottom.
+    fn y_extend_to_line_height(&self, top: i32, bottom: i32) -> (i32, i32) {
+        let Some(line_height) = self.line_height else {
+            return (top, bottom);
+        };
+
+        let height = bottom.saturating_sub(top);
+        let leading = line_height
+            .try_into()
+            .unwrap_or(i32::MAX)
+            .saturating_sub(height);
+        let top = top.saturating_sub(leading / 2);
+        let bottom = top.saturating_add_unsigned(line_height);
+
+        (top, bottom)
+    }
+
+    /// Returns the characters that are drawn centered in cells of the same width along with that
+    /// width, or `None` if there are none.
+    fn tabular(&self) -> Option<(&'b str, f32)> {
+        let chars = self.tabular_chars;
+
+        (!chars.is_empty()).then_some((chars, self.tabular_width))
+    }
+
+    /// Returns the settings that change the advance widths of glyph clusters.
+    fn spacing(&self) -> Spacing<'b> {
+        Spacing {
+            tabular: self.tabular(),
+            letter_spacing: self.letter_spacing,
+            word_spacing: self.word_spacing,
+        }
+    }
+
+    /// Returns the ellipsis, falling back to three periods if the bitmap font does not have the
+    /// glyphs for it.
+    fn ellipsis_or_fallback(&self) -> &'static str {
+        if self.font.covers(self.ellipsis) {
+            self.ellipsis
+        } else {
+            "..."
+        }
+    }
+
+    /// Returns the parts of the specified text that are drawn before and after the ellipsis when
+    /// the text is truncated to fit within the specified width in pixels, without scaling, or
+    /// `None` if the text fits.
+    fn truncated<'s>(
+        &self,
+        text: &'s str,
+        max_width: u32,
+        truncation: Truncation,
+    ) -> Option<Truncated<'s>> {
+        let spacing = self.spacing();
+        let is_boundary = |index: usize| !text[index..].starts_with(layout::is_grapheme_extend);
+        let end_x = |text| {
+            let clusters = clusters_of_chars(self.font, text, 0.0, spacing);
+
+            clusters.last().map_or(0.0, |(_, _, end_x)| end_x)
+        };
+
+        let width = end_x(text);
+        let max_width = max_width as f32;
+        if width <= max_width {
+            return None;
+        }
+
+        let ellipsis_width = end_x(self.ellipsis_or_fallback());
+        let available_width = max_width - ellipsis_width;
+        let head_width = match truncation {
+            Truncation::End => available_width,
+            Truncation::Start => 0.0,
+            Truncation::Middle => available_width / 2.0,
+        };
+        let head = clusters_of_chars(self.font, text, 0.0, spacing)
+            .take_while(|(_, _, end_x)| *end_x <= head_width)
+            .filter(|(index, _, _)| is_boundary(*index))
+            .last()
+            .map_or("", |(index, _, _)| text[..index].trim_end_matches(' '));
+
+        let head_width = end_x(head);
+        let tail_width = match truncation {
+            Truncation::End => 0.0,
+            Truncation::Start | Truncation::Middle => available_width - head_width,
+        };
+        let tail = carets_of_chars(self.font, text, 0.0, spacing)
+            .find(|(index, x)| {
+                *index >= head.len() && width - x <= tail_width && is_boundary(*index)
+            })
+            .map_or("", |(index, _)| text[index..].trim_start_matches(' '));
+
+        Some(Truncated {
+            head,
+            head_width,
+            ellipsis_width,
+            tail,
+        })
+    }
+
+    /// Returns the offset from the baseline to the middle of the em box, which is where sideways
+    /// text runs are centered in vertical writing mode.
+    fn central_offset(&self) -> i32 {
+        let metrics = self.font.metrics();
+
+        ((metrics.ascender + metrics.descender) / 2.0) as i32
+    }
+
+    /// Returns the area of the column for vertical writing mode that starts at the specified
+    /// position on its center line, with the specified height.
+    fn vertical_column(&self, position: Point, height: u32) -> Rectangle {
+        let metrics = self.font.metrics();
+        let y = position.y.saturating_add(self.central_offset());
+        let top = y.saturating_sub(metrics.y_offset(Baseline::Top));
+        let bottom = y.saturating_sub(metrics.y_offset(Baseline::Bottom));
+        let (top, bottom) = self.y_extend_to_line_height(top, bottom);
+        let width = bottom.saturating_sub(top).try_into().unwrap_or_default();
+        let line_strip = Rectangle {
+            top_left: Point::new(position.x, top),
+            size: Size::new(height, width),
+        };
+
+        line_strip.rotate_about(position, Rotation::Deg90)
+    }
+
+    /// Draws the underline and the strikethrough decorations that are enabled, starting at the
+    /// specified _x_-coordinate and baseline, with the specified width.
+    fn draw_decorations<D>(
+        &self,
+        x: i32,
+        y: i32,
+        width: u32,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = T>,
+    {
+        if let Some(stroke_color) = self.underline_color() {
+            let top = y.saturating_sub(self.font.underline().y_offset());
+            let height = self.font.underline().stroke_width();
+            let underline_style = PrimitiveStyle::with_fill(stroke_color);
+            let underline = Rectangle {
+                top_left: Point::new(x, top),
+                size: Size::new(width, height),
+            };
+
+            underline.draw_styled(&underline_style, target)?;
+        }
+
+        if let Some(stroke_color) = self.strikethrough_color() {
+            let top = y.saturating_sub(self.font.strikethrough().y_offset());
+            let height = self.font.strikethrough().stroke_width();
+            let strikethrough_style = PrimitiveStyle::with_fill(stroke_color);
+            let strikethrough = Rectangle {
+                top_left: Point::new(x, top),
+                size: Size::new(width, height),
+            };
+
+            strikethrough.draw_styled(&strikethrough_style, target)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T, C, const N: usize, F> Clone for BitmapFontStyle<'a, '_, T, C, N, F>
+where
+    C: PixelColor + From<C::Raw>,
+    T: PixelColor + Default + Invert + Screen,
+    F: BitmapFontData<'a, C, N>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            font: self.font,
+            text_color: self.text_color,
+            background_color: self.background_color,
+            underline_color: self.underline_color,
+            strikethrough_color: self.strikethrough_color,
+            outline_color: self.outline_color,
+            shadow_color: self.shadow_color,
+            shadow_offset: self.shadow_offset,
+            rotation: self.rotation,
+            tate_chu_yoko: self.tate_chu_yoko,
+            tabular_chars: self.tabular_chars,
+            tabular_width: self.tabular_width,
+            letter_spacing: self.letter_spacing,
+            word_spacing: self.word_spacing,
+            line_height: self.line_height,
+            scale: self.scale,
+            ellipsis: self.ellipsis,
+            phantom: PhantomData,
+        }
+    }
 }
 
-impl<'a, T, C, const N: usize> CharacterStyle for BitmapFontStyle<'a, '_, T, C, N>
+impl<'a, T, C, const N: usize, F> CharacterStyle for BitmapFontStyle<'a, '_, T, C, N, F>
 where
     C: PixelColor + From<C::Raw>,
     T: PixelColor + Default + Invert + Screen,
+    F: BitmapFontData<'a, C, N>,
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
     type Color = T;
@@ -117,9 +631,11 @@ macro_rules! impl_text_renderer {
         )*
     ) => {
         $(
-            impl<T, const N: usize> TextRenderer for BitmapFontStyle<'_, '_, T, $color_type, N>
+            impl<'a, T, const N: usize, F> TextRenderer
+                for BitmapFontStyle<'a, '_, T, $color_type, N, F>
             where
                 T: PixelColor + Default + Invert + Screen,
+                F: BitmapFontData<'a, $color_type, N>,
                 Colormap<T, $array_length>: Linear<T>,
             {
                 type Color = T;
@@ -134,331 +650,2684 @@ macro_rules! impl_text_renderer {
                 where
                     D: DrawTarget<Color = Self::Color>,
                 {
-                    let mut right = position.x;
-                    let mut x = position.x as f32;
-                    let y = position.y.saturating_add(self.font.metrics.y_offset(baseline));
-                    let top = y.saturating_sub(self.font.metrics.y_offset(Baseline::Top));
-                    let bottom = y.saturating_sub(self.font.metrics.y_offset(Baseline::Bottom));
-                    let height = bottom.saturating_sub(top).try_into().unwrap_or_default();
-                    let background_style = PrimitiveStyle::with_fill(self.background_color());
-                    let line_strip = Rectangle {
-                        top_left: Point::new(position.x, top),
-                        size: Size::new(u32::MAX, height),
-                    };
-
-                    let colormap = Colormap::linear(self.background_color(), self.text_color());
-                    let images = images_of_chars(&self.font.charmap, text, &mut x, y as f32);
-                    let mut image_before_overlays: Option<Image<_>> = None;
-                    let mut previous_image: Option<Image<_>> = None;
-                    let mut previous_right = right;
-                    for (image, is_overlay) in images {
-                        let image_box = image.bounding_box();
-                        let x = image_box.top_left.x.saturating_add_unsigned(image_box.size.width);
-                        if x > right {
-                            right = x;
+                    match (self.rotation, self.scale()) {
+                        (Rotation::Deg0, 1) => {
+                            self.draw_string_unrotated(text, position, baseline, target)
                         }
+                        (rotation, scale) => {
+                            let mut target = target.rotated(position, rotation);
+                            let mut target = target.scaled(position, scale);
+                            let next_position =
+                                self.draw_string_unrotated(text, position, baseline, &mut target)?;
 
-                        if !is_overlay && image_before_overlays.is_some() {
-                            previous_image = image_before_overlays.take();
+                            Ok(self.transform_about(next_position, position))
                         }
+                    }
+                }
 
-                        let line_piece = line_strip.left_of(&image_box);
-                        let clip_area = if let Some(previous_image) = previous_image.as_ref() {
-                            let previous_image_box = previous_image.bounding_box();
-                            let previous_right_half = previous_image_box.indent_to(previous_right);
-                            let line_piece = line_piece.right_of(&previous_right_half);
-                            line_piece.draw_styled(&background_style, target)?;
-
-                            let left = previous_right_half.left_of(&image_box);
-                            let left = left.y_extend(top, bottom);
-                            let right = previous_right_half.right_of(&image_box);
-                            let right = right.y_extend(top, bottom);
-                            let middle = previous_right_half.left_of(&right).right_of(&left);
-                            let middle = middle.y_extend(top, bottom);
-                            let above = middle.above(&image_box);
-                            let below = middle.below(&image_box);
-                            for clip_area in [left, right, above, below] {
-                                let mut adapter = target.value_mapped(&colormap);
-                                previous_image.clipped(&clip_area).draw(&mut adapter)?;
-
-                                let above = clip_area.above(&previous_image_box);
-                                let below = clip_area.below(&previous_image_box);
-                                for fill_area in [above, below] {
-                                    fill_area.draw_styled(&background_style, target)?;
-                                }
-                            }
-
-                            let image_box = if is_overlay {
-                                let image_box = image_box.y_reduce(top, bottom);
-                                let clip_area = image_box.left_of(&previous_image_box);
-                                let mut adapter = target.value_mapped(&colormap);
-                                image.clipped(&clip_area).draw(&mut adapter)?;
-
-                                image_box
-                            } else {
-                                image_box.left_half()
-                            };
-
-                            let column = previous_image_box.y_extend(top, bottom);
-                            let above = column.above(&previous_image_box);
-                            let below = column.below(&previous_image_box);
-                            for clip_area in [above, below] {
-                                let mut adapter = target.value_mapped(&colormap);
-                                image.clipped(&clip_area).draw(&mut adapter)?;
-                            }
-
-                            image.mixed(previous_image, &colormap).draw(target)?;
-
-                            image_box.right_of(&previous_image_box)
-                        } else {
-                            line_piece.draw_styled(&background_style, target)?;
-
-                            image_box.left_half()
-                        };
-                        let mut adapter = target.value_mapped(&colormap);
-                        image.clipped(&clip_area).draw(&mut adapter)?;
-
-                        let right = clip_area.indent_to(previous_right);
-                        let column = right.y_extend(top, bottom);
-                        let above = column.above(&image_box);
-                        let below = column.below(&image_box);
-                        for fill_area in [above, below] {
-                            fill_area.draw_styled(&background_style, target)?;
+                fn draw_whitespace<D>(
+                    &self,
+                    width: u32,
+                    position: Point,
+                    baseline: Baseline,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    D: DrawTarget<Color = Self::Color>,
+                {
+                    match (self.rotation, self.scale()) {
+                        (Rotation::Deg0, 1) => {
+                            self.draw_whitespace_unrotated(width, position, baseline, target)
                         }
+                        (rotation, scale) => {
+                            let TextMetrics { bounding_box, .. } =
+                                self.measure_string_unrotated("", position, baseline);
+                            let mut clip_area = bounding_box.scale_about(position, scale);
+                            clip_area.size.width = width;
 
-                        let previous_image = previous_image.replace(image);
-                        if is_overlay && image_before_overlays.is_none() {
-                            image_before_overlays = previous_image;
-                        }
+                            let mut target = target.rotated(position, rotation);
+                            let mut target = target.clipped(&clip_area);
+                            let mut target = target.scaled(position, scale);
+                            self.draw_whitespace_unrotated(
+                                width.div_ceil(scale),
+                                position,
+                                baseline,
+                                &mut target,
+                            )?;
 
-                        previous_right = right.top_left.x.saturating_add_unsigned(right.size.width);
-                    }
+                            let next_position = position.x.saturating_add_unsigned(width);
+                            let next_position = Point::new(next_position, position.y);
 
-                    if let Some(previous_image) = previous_image.take() {
-                        let previous_image_box = previous_image.bounding_box();
-                        let previous_right_half = previous_image_box.indent_to(previous_right);
-                        let mut adapter = target.value_mapped(&colormap);
-                        previous_image.clipped(&previous_right_half).draw(&mut adapter)?;
-
-                        let column = previous_right_half.y_extend(top, bottom);
-                        let above = column.above(&previous_image_box);
-                        let below = column.below(&previous_image_box);
-                        for fill_area in [above, below] {
-                            fill_area.draw_styled(&background_style, target)?;
+                            Ok(rotation.rotate_about(next_position, position))
                         }
                     }
+                }
 
-                    let width = (x as i32).saturating_sub(right);
-                    let width = width.try_into().unwrap_or_default();
-                    let line_piece = Rectangle {
-                        top_left: Point::new(right, top),
-                        size: Size::new(width, height),
-                    };
-
-                    let next_position = Point::new(x as i32, position.y);
-                    line_piece.draw_styled(&background_style, target)?;
-
-                    let right = i32::max(x as i32, right);
-                    let width = right.saturating_sub(position.x);
-                    let width = width.try_into().unwrap_or_default();
+                fn measure_string(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    baseline: Baseline
+                ) -> TextMetrics {
+                    let TextMetrics { bounding_box, next_position } =
+                        self.measure_string_unrotated(text, position, baseline);
+                    let bounding_box = bounding_box.scale_about(position, self.scale());
+                    let bounding_box = bounding_box.rotate_about(position, self.rotation);
+                    let next_position = self.transform_about(next_position, position);
 
-                    if let Some(stroke_color) = self.underline_color() {
-                        let top = y.saturating_sub(self.font.underline.y_offset());
-                        let height = self.font.underline.stroke_width();
-                        let underline_style = PrimitiveStyle::with_fill(stroke_color);
-                        let underline = Rectangle {
-                            top_left: Point::new(position.x, top),
-                            size: Size::new(width, height),
-                        };
+                    TextMetrics { bounding_box, next_position }
+                }
 
-                        underline.draw_styled(&underline_style, target)?;
-                    }
+                fn line_height(&self) -> u32 {
+                    self.unscaled_line_height().saturating_mul(self.scale())
+                }
+            }
 
-                    if let Some(stroke_color) = self.strikethrough_color() {
-                        let top = y.saturating_sub(self.font.strikethrough.y_offset());
-                        let height = self.font.strikethrough.stroke_width();
-                        let strikethrough_style = PrimitiveStyle::with_fill(stroke_color);
-                        let strikethrough = Rectangle {
-                            top_left: Point::new(position.x, top),
-                            size: Size::new(width, height),
-                        };
+            impl<'a, T, const N: usize, F> BitmapFontStyle<'a, '_, T, $color_type, N, F>
+            where
+                T: PixelColor + Default + Invert + Screen,
+                F: BitmapFontData<'a, $color_type, N>,
+                Colormap<T, $array_length>: Linear<T>,
+            {
+                /// Draws a string without filling in the background, blending the anti-aliased
+                /// pixels of each glyph with the pixels that are already on the draw target, and
+                /// returns the position of the next string. The background color is not used.
+                pub fn draw_string_transparent<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    baseline: Baseline,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    T: Blend,
+                    D: ReadPixel<Color = T>,
+                {
+                    match (self.rotation, self.scale()) {
+                        (Rotation::Deg0, 1) => {
+                            self.draw_string_transparent_unrotated(text, position, baseline, target)
+                        }
+                        (rotation, scale) => {
+                            let mut target = target.rotated(position, rotation);
+                            let mut target = target.scaled(position, scale);
+                            let next_position = self.draw_string_transparent_unrotated(
+                                text,
+                                position,
+                                baseline,
+                                &mut target,
+                            )?;
 
-                        strikethrough.draw_styled(&strikethrough_style, target)?;
+                            Ok(self.transform_about(next_position, position))
+                        }
                     }
-
-                    Ok(next_position)
                 }
 
-                fn draw_whitespace<D>(
+                /// Draws a string in vertical writing mode, from top to bottom, and returns the
+                /// position of the next string. The specified position is at the top of the
+                /// column, on its center line.
+                ///
+                /// Characters that are upright in vertical text are stacked downward, one glyph
+                /// cluster per cell, using their vertical advances. Runs of other characters, such
+                /// as Latin letters, are drawn sideways, rotated by 90 degrees; runs of up to
+                /// [`tate_chu_yoko`](Self::tate_chu_yoko) digits are drawn side by side in a
+                /// single cell instead. Decorations are not drawn, and the rotation of the style
+                /// is not applied.
+                pub fn draw_string_vertical<D>(
                     &self,
-                    width: u32,
+                    text: &str,
                     position: Point,
-                    baseline: Baseline,
                     target: &mut D,
                 ) -> Result<Point, D::Error>
                 where
-                    D: DrawTarget<Color = Self::Color>,
+                    D: DrawTarget<Color = T>,
                 {
-                    let x = position.x as f32 + width as f32;
-                    let y = position.y.saturating_add(self.font.metrics.y_offset(baseline));
-                    let top = y.saturating_sub(self.font.metrics.y_offset(Baseline::Top));
-                    let bottom = y.saturating_sub(self.font.metrics.y_offset(Baseline::Bottom));
-                    let height = bottom.saturating_sub(top).try_into().unwrap_or_default();
-                    let background_style = PrimitiveStyle::with_fill(self.background_color());
-                    let line_piece = Rectangle {
-                        top_left: Point::new(position.x, top),
-                        size: Size::new(width, height),
-                    };
+                    let scale = self.scale();
+                    let mut target = target.scaled(position, scale);
+                    let next_position =
+                        self.draw_string_vertical_unscaled(text, position, &mut target)?;
 
-                    let next_position = Point::new(x as i32, position.y);
-                    line_piece.draw_styled(&background_style, target)?;
+                    Ok(next_position.scale_about(position, scale))
+                }
 
-                    if let Some(stroke_color) = self.underline_color() {
-                        let top = y.saturating_sub(self.font.underline.y_offset());
-                        let height = self.font.underline.stroke_width();
-                        let underline_style = PrimitiveStyle::with_fill(stroke_color);
-                        let underline = Rectangle {
-                            top_left: Point::new(position.x, top),
-                            size: Size::new(width, height),
-                        };
+                /// Measures a string in vertical writing mode, see
+                /// [`draw_string_vertical`](Self::draw_string_vertical). The bounding box is the
+                /// area of the column that the string takes up.
+                pub fn measure_string_vertical(&self, text: &str, position: Point) -> TextMetrics {
+                    let TextMetrics { bounding_box, next_position } =
+                        self.measure_string_vertical_unscaled(text, position);
+                    let bounding_box = bounding_box.scale_about(position, self.scale());
+                    let next_position = next_position.scale_about(position, self.scale());
 
-                        underline.draw_styled(&underline_style, target)?;
-                    }
+                    TextMetrics { bounding_box, next_position }
+                }
 
-                    if let Some(stroke_color) = self.strikethrough_color() {
-                        let top = y.saturating_sub(self.font.strikethrough.y_offset());
-                        let height = self.font.strikethrough.stroke_width();
-                        let strikethrough_style = PrimitiveStyle::with_fill(stroke_color);
-                        let strikethrough = Rectangle {
-                            top_left: Point::new(position.x, top),
-                            size: Size::new(width, height),
-                        };
+                /// Draws a string as [`draw_string_vertical`](Self::draw_string_vertical) does,
+                /// without scaling.
+                fn draw_string_vertical_unscaled<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    let style = Self {
+                        underline_color: DecorationColor::None,
+                        strikethrough_color: DecorationColor::None,
+                        ..self.clone()
+                    };
+                    let ascender = self.font.metrics().ascender as i32;
+                    let background_style = PrimitiveStyle::with_fill(self.background_color());
+                    let mut y = position.y as f32;
+                    for run in vertical_runs(self.font, text, self.tate_chu_yoko) {
+                        let top = y as i32;
+                        y += self.vertical_advance(run);
+                        let origin = Point::new(position.x, top);
+                        match run {
+                            VerticalRun::Upright(slice) | VerticalRun::TateChuYoko(slice) => {
+                                let height = (y as i32).saturating_sub(top);
+                                let height = height.try_into().unwrap_or_default();
+                                let cell = self.vertical_column(origin, height);
+                                let baseline = Baseline::Alphabetic;
+                                let TextMetrics { next_position, .. } =
+                                    self.measure_string_unrotated(slice, Point::zero(), baseline);
+                                let x = position.x.saturating_sub(next_position.x / 2);
+                                let width = next_position.x.try_into().unwrap_or_default();
+                                let line_piece = Rectangle {
+                                    top_left: Point::new(x, top),
+                                    size: Size::new(width, height),
+                                };
+                                let left = cell.left_of(&line_piece);
+                                let right = cell.right_of(&line_piece);
+                                for fill_area in [left, right] {
+                                    fill_area.draw_styled(&background_style, target)?;
+                                }
 
-                        strikethrough.draw_styled(&strikethrough_style, target)?;
+                                let position = Point::new(x, top.saturating_add(ascender));
+                                let mut target = target.clipped(&cell);
+                                style.draw_string_unrotated(
+                                    slice,
+                                    position,
+                                    Baseline::Alphabetic,
+                                    &mut target,
+                                )?;
+                            }
+                            VerticalRun::Sideways(slice) => {
+                                let y = top.saturating_add(self.central_offset());
+                                let position = Point::new(position.x, y);
+                                let mut target = target.rotated(origin, Rotation::Deg90);
+                                style.draw_string_unrotated(
+                                    slice,
+                                    position,
+                                    Baseline::Alphabetic,
+                                    &mut target,
+                                )?;
+                            }
+                        }
                     }
 
-                    Ok(next_position)
+                    Ok(Point::new(position.x, y as i32))
                 }
 
-                fn measure_string(
+                /// Measures a string as
+                /// [`measure_string_vertical`](Self::measure_string_vertical) does, without
+                /// scaling.
+                fn measure_string_vertical_unscaled(
                     &self,
                     text: &str,
                     position: Point,
-                    baseline: Baseline
                 ) -> TextMetrics {
-                    let mut right = position.x;
-                    let mut x = position.x as f32;
-                    let y = position.y.saturating_add(self.font.metrics.y_offset(baseline));
-                    let top = y.saturating_sub(self.font.metrics.y_offset(Baseline::Top));
-                    let bottom = y.saturating_sub(self.font.metrics.y_offset(Baseline::Bottom));
-                    let height = bottom.saturating_sub(top).try_into().unwrap_or_default();
-                    let images = images_of_chars(&self.font.charmap, text, &mut x, y as f32);
-                    for (image, _) in images {
-                        let image_box = image.bounding_box();
-                        let x = image_box.top_left.x.saturating_add_unsigned(image_box.size.width);
-                        if x > right {
-                            right = x;
-                        }
+                    let mut y = position.y as f32;
+                    for run in vertical_runs(self.font, text, self.tate_chu_yoko) {
+                        y += self.vertical_advance(run);
                     }
 
-                    let next_position = Point::new(x as i32, position.y);
-                    let width = right.saturating_sub(position.x).try_into().unwrap_or_default();
-                    let bounding_box = Rectangle {
-                        top_left: Point::new(position.x, top),
-                        size: Size::new(width, height),
-                    };
+                    let next_position = Point::new(position.x, y as i32);
+                    let height = next_position.y.saturating_sub(position.y);
+                    let height = height.try_into().unwrap_or_default();
+                    let bounding_box = self.vertical_column(position, height);
 
                     TextMetrics { bounding_box, next_position }
                 }
 
-                fn line_height(&self) -> u32 {
-                    self.font.metrics.line_height()
-                }
-            }
-        )*
-    }
-}
+                /// Returns the value for advancing the position along the _y_-axis for the
+                /// specified run in vertical writing mode, falling back to the size of the em box
+                /// for glyph clusters that have no vertical advance.
+                fn vertical_advance(&self, run: VerticalRun<'_>) -> f32 {
+                    let metrics = self.font.metrics();
+                    let em = metrics.ascender - metrics.descender;
+                    match run {
+                        VerticalRun::Upright(slice) => {
+                            let (entry, _) = self.font.get_normalized(slice);
+                            let advance_height = entry.advance_height();
+                            if advance_height > 0.0 { advance_height } else { em }
+                        }
+                        VerticalRun::TateChuYoko(_) => em,
+                        VerticalRun::Sideways(slice) => {
+                            let baseline = Baseline::Alphabetic;
+                            let TextMetrics { next_position, .. } =
+                                self.measure_string_unrotated(slice, Point::zero(), baseline);
 
-impl_text_renderer! {
+                            next_position.x as f32
+                        }
+                    }
+                }
+
+                /// Draws a string that is truncated to fit within the specified width, replacing
+                /// the characters that do not fit with the [`ellipsis`](Self::ellipsis) at the
+                /// position that the specified truncation selects, and returns the position of the
+                /// next string. A string that fits is drawn as [`draw_string`] does. See the
+                /// [`layout`] module.
+                ///
+                /// [`draw_string`]: TextRenderer::draw_string
+                pub fn draw_truncated<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    baseline: Baseline,
+                    max_width: u32,
+                    truncation: Truncation,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    match (self.rotation, self.scale()) {
+                        (Rotation::Deg0, 1) => self.draw_truncated_unrotated(
+                            text,
+                            position,
+                            baseline,
+                            max_width,
+                            truncation,
+                            target,
+                        ),
+                        (rotation, scale) => {
+                            let mut target = target.rotated(position, rotation);
+                            let mut target = target.scaled(position, scale);
+                            let next_position = self.draw_truncated_unrotated(
+                                text,
+                                position,
+                                baseline,
+                                max_width / scale,
+                                truncation,
+                                &mut target,
+                            )?;
+
+                            Ok(self.transform_about(next_position, position))
+                        }
+                    }
+                }
+
+                /// Draws a string as [`draw_truncated`](Self::draw_truncated) does, without
+                /// rotation or scaling.
+                fn draw_truncated_unrotated<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    baseline: Baseline,
+                    max_width: u32,
+                    truncation: Truncation,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    let Some(truncated) = self.truncated(text, max_width, truncation) else {
+                        return self.draw_string_unrotated(text, position, baseline, target);
+                    };
+
+                    let Truncated { head, head_width, ellipsis_width, tail } = truncated;
+                    let x = position.x as f32 + head_width;
+                    let ellipsis_position = Point::new(x as i32, position.y);
+                    let x = x + ellipsis_width;
+                    let tail_position = Point::new(x as i32, position.y);
+                    self.draw_string_unrotated(head, position, baseline, target)?;
+                    let ellipsis = self.ellipsis_or_fallback();
+                    self.draw_string_unrotated(ellipsis, ellipsis_position, baseline, target)?;
+
+                    self.draw_string_unrotated(tail, tail_position, baseline, target)
+                }
+
+                /// Draws a paragraph, breaking it into lines that are no wider than the specified
+                /// width, and returns the position of the next paragraph. The specified position is
+                /// the top-left corner of the first line; the lines are laid out from top to bottom
+                /// using the line height and the specified justification. See the
+                /// [`layout`] module.
+                pub fn draw_paragraph<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    width: u32,
+                    justification: Justification,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    match (self.rotation, self.scale()) {
+                        (Rotation::Deg0, 1) => self.draw_paragraph_unrotated(
+                            text,
+                            position,
+                            width,
+                            justification,
+                            target,
+                        ),
+                        (rotation, scale) => {
+                            let mut target = target.rotated(position, rotation);
+                            let mut target = target.scaled(position, scale);
+                            let next_position = self.draw_paragraph_unrotated(
+                                text,
+                                position,
+                                width / scale,
+                                justification,
+                                &mut target,
+                            )?;
+
+                            Ok(self.transform_about(next_position, position))
+                        }
+                    }
+                }
+
+                /// Draws a paragraph as [`draw_paragraph`](Self::draw_paragraph) does, without
+                /// rotation or scaling.
+                fn draw_paragraph_unrotated<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    width: u32,
+                    justification: Justification,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    let spacing = self.spacing();
+                    let line_height = self.unscaled_line_height();
+                    let lines = layout::lines(text, width, |paragraph| {
+                        carets_of_chars(self.font, paragraph, 0.0, spacing)
+                    });
+
+                    let mut y = position.y;
+                    for line in lines {
+                        let extra_width = width.saturating_sub(line.width);
+                        let x_offset = match justification {
+                            Justification::Left | Justification::Full => 0,
+                            Justification::Center => extra_width / 2,
+                            Justification::Right => extra_width,
+                        };
+                        let x = position.x.saturating_add_unsigned(x_offset);
+                        let position = Point::new(x, y);
+                        match justification {
+                            Justification::Full if !line.ends_paragraph => {
+                                self.draw_line_justified(line.text, position, extra_width, target)?;
+                            }
+                            _ => {
+                                let baseline = Baseline::Top;
+                                self.draw_string_unrotated(line.text, position, baseline, target)?;
+                            }
+                        }
+
+                        y = y.saturating_add_unsigned(line_height);
+                    }
+
+                    Ok(Point::new(position.x, y))
+                }
+
+                /// Draws a line of text, distributing the specified extra width evenly between
+                /// words that are separated by spaces or, if there are none, between glyph
+                /// clusters. The gaps are filled in as whitespace.
+                fn draw_line_justified<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    extra_width: u32,
+                    target: &mut D,
+                ) -> Result<(), D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    let spacing = self.spacing();
+                    let has_spaces = text.contains(' ');
+                    let carets = || carets_of_chars(self.font, text, position.x as f32, spacing);
+                    let gaps = carets().skip(1).scan(0, |start, (index, _)| {
+                        let cluster = &text[*start..index];
+                        *start = index;
+
+                        Some(index < text.len() && (!has_spaces || cluster.starts_with(' ')))
+                    });
+                    let gaps = gaps.filter(|is_gap| *is_gap).count() as u64;
+                    if gaps == 0 {
+                        self.draw_string_unrotated(text, position, Baseline::Top, target)?;
+
+                        return Ok(());
+                    }
+
+                    let offset = |gap: u64| (u64::from(extra_width) * gap / gaps) as u32;
+                    let mut gap = 0;
+                    let mut start = (0, position.x);
+                    let mut previous = 0;
+                    for (index, x) in carets().skip(1) {
+                        let cluster = &text[previous..index];
+                        previous = index;
+                        let is_end = index == text.len();
+                        if !is_end && has_spaces && !cluster.starts_with(' ') {
+                            continue;
+                        }
+
+                        let (start_index, start_x) = start;
+                        let slice = &text[start_index..index];
+                        let x_offset = start_x.saturating_add_unsigned(offset(gap));
+                        let segment_position = Point::new(x_offset, position.y);
+                        let baseline = Baseline::Top;
+                        let next_position =
+                            self.draw_string_unrotated(slice, segment_position, baseline, target)?;
+
+                        if !is_end {
+                            gap += 1;
+                            let next_x = (x as i32).saturating_add_unsigned(offset(gap));
+                            let width = next_x.saturating_sub(next_position.x);
+                            if let Ok(width @ 1..) = u32::try_from(width) {
+                                self.draw_whitespace_unrotated(
+                                    width,
+                                    next_position,
+                                    Baseline::Top,
+                                    target,
+                                )?;
+                            }
+
+                            start = (index, x as i32);
+                        }
+                    }
+
+                    Ok(())
+                }
+
+                /// Draws a string as [`draw_string`](TextRenderer::draw_string) does, without
+                /// rotation.
+                fn draw_string_unrotated<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    baseline: Baseline,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    let mut x = position.x as f32;
+                    let metrics = self.font.metrics();
+                    let y = position.y.saturating_add(metrics.y_offset(baseline));
+                    let top = y.saturating_sub(metrics.y_offset(Baseline::Top));
+                    let bottom = y.saturating_sub(metrics.y_offset(Baseline::Bottom));
+                    let (top, bottom) = self.y_extend_to_shadow(top, bottom);
+                    let (top, bottom) = self.y_extend_to_line_height(top, bottom);
+                    let height = bottom.saturating_sub(top).try_into().unwrap_or_default();
+                    let (left, _) = self.x_extend_to_shadow(position.x, position.x);
+                    let background_style = PrimitiveStyle::with_fill(self.background_color());
+                    let line_strip = Rectangle {
+                        top_left: Point::new(left, top),
+                        size: Size::new(u32::MAX, height),
+                    };
+
+                    let colormap = Colormap::linear(self.background_color(), self.text_color());
+                    let images = images_of_chars(self.font, text, &mut x, y as f32, self.spacing());
+                    let shadow_offset = self.shadow_offset();
+                    let right = match (self.font.subpixel(), shadow_offset, self.outline_color) {
+                        (None, None, None) => {
+                            let images = images
+                                .into_iter()
+                                .map(|(image, _, is_overlay)| (image, is_overlay));
+
+                            draw_images(
+                                images,
+                                line_strip,
+                                &background_style,
+                                target,
+                                |image, clip_area, target| {
+                                    let mut adapter = target.value_mapped(&colormap);
+
+                                    image.clipped(clip_area).draw(&mut adapter)
+                                },
+                                |image, previous_image, target| {
+                                    image.mixed(previous_image, &colormap).draw(target)
+                                },
+                            )?
+                        }
+                        (None, shadow_offset, outline_color) => {
+                            let text_color = self.text_color();
+                            let (bottom_color, middle_color) = match shadow_offset {
+                                Some(_) => (self.shadow_color, outline_color),
+                                None => (outline_color, None),
+                            };
+                            let bottom_color = bottom_color.unwrap_or(text_color);
+                            let middle_color = middle_color.unwrap_or(text_color);
+                            let colormaps = [
+                                &Colormap::linear(self.background_color(), bottom_color),
+                                &Colormap::linear(middle_color.invert(), middle_color),
+                                &Colormap::linear(text_color.invert(), text_color),
+                            ];
+                            let images = images.into_iter().map(|(image, outline, is_overlay)| {
+                                let layers = match (shadow_offset, outline_color) {
+                                    (Some(offset), Some(_)) => {
+                                        let is_empty = outline.bounding_box().is_zero_sized();
+                                        let shadow = if is_empty { &image } else { &outline };
+
+                                        [shadow.add_offset(offset.x, offset.y), outline]
+                                    }
+                                    (Some(offset), None) => {
+                                        [image.add_offset(offset.x, offset.y), Image::NULL]
+                                    }
+                                    (None, _) => [outline, Image::NULL],
+                                };
+                                let [bottom, middle] = &layers;
+                                let image = image.layered([bottom, middle], colormaps);
+
+                                (image, is_overlay)
+                            });
+
+                            draw_images(
+                                images,
+                                line_strip,
+                                &background_style,
+                                target,
+                                |image, clip_area, target| image.clipped(clip_area).draw(target),
+                                |image, previous_image, target| {
+                                    image.draw_mixed(previous_image, target)
+                                },
+                            )?
+                        }
+                        (Some(order), _, _) => {
+                            let images = images.into_iter().map(|(image, _, is_overlay)| {
+                                (image.subpixel_mapped(order, &colormap), is_overlay)
+                            });
+
+                            draw_images(
+                                images,
+                                line_strip,
+                                &background_style,
+                                target,
+                                |image, clip_area, target| image.clipped(clip_area).draw(target),
+                                |image, previous_image, target| {
+                                    draw_mixed(image, previous_image, &colormap, target)
+                                },
+                            )?
+                        }
+                    };
+
+                    let shadow_x = shadow_offset.map_or(0, |offset| offset.x.max(0));
+                    let next_x = (x as i32).saturating_add(shadow_x);
+                    let width = next_x.saturating_sub(right);
+                    let width = width.try_into().unwrap_or_default();
+                    let line_piece = Rectangle {
+                        top_left: Point::new(right, top),
+                        size: Size::new(width, height),
+                    };
+
+                    let next_position = Point::new(next_x, position.y);
+                    line_piece.draw_styled(&background_style, target)?;
+
+                    let right = i32::max(x as i32, right);
+                    let width = right.saturating_sub(position.x);
+                    let width = width.try_into().unwrap_or_default();
+                    self.draw_decorations(position.x, y, width, target)?;
+
+                    Ok(next_position)
+                }
+
+                /// Draws whitespace as [`draw_whitespace`](TextRenderer::draw_whitespace) does,
+                /// without rotation or scaling.
+                fn draw_whitespace_unrotated<D>(
+                    &self,
+                    width: u32,
+                    position: Point,
+                    baseline: Baseline,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    D: DrawTarget<Color = T>,
+                {
+                    let x = position.x as f32 + width as f32;
+                    let metrics = self.font.metrics();
+                    let y = position.y.saturating_add(metrics.y_offset(baseline));
+                    let top = y.saturating_sub(metrics.y_offset(Baseline::Top));
+                    let bottom = y.saturating_sub(metrics.y_offset(Baseline::Bottom));
+                    let (top, bottom) = self.y_extend_to_shadow(top, bottom);
+                    let (top, bottom) = self.y_extend_to_line_height(top, bottom);
+                    let height = bottom.saturating_sub(top).try_into().unwrap_or_default();
+                    let background_style = PrimitiveStyle::with_fill(self.background_color());
+                    let line_piece = Rectangle {
+                        top_left: Point::new(position.x, top),
+                        size: Size::new(width, height),
+                    };
+
+                    let next_position = Point::new(x as i32, position.y);
+                    line_piece.draw_styled(&background_style, target)?;
+                    self.draw_decorations(position.x, y, width, target)?;
+
+                    Ok(next_position)
+                }
+
+                /// Measures a string as [`measure_string`](TextRenderer::measure_string) does,
+                /// without rotation or scaling.
+                fn measure_string_unrotated(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    baseline: Baseline
+                ) -> TextMetrics {
+                    let mut right = position.x;
+                    let mut x = position.x as f32;
+                    let metrics = self.font.metrics();
+                    let y = position.y.saturating_add(metrics.y_offset(baseline));
+                    let top = y.saturating_sub(metrics.y_offset(Baseline::Top));
+                    let bottom = y.saturating_sub(metrics.y_offset(Baseline::Bottom));
+                    let (top, bottom) = self.y_extend_to_shadow(top, bottom);
+                    let (top, bottom) = self.y_extend_to_line_height(top, bottom);
+                    let height = bottom.saturating_sub(top).try_into().unwrap_or_default();
+                    let images = images_of_chars(self.font, text, &mut x, y as f32, self.spacing());
+                    let subpixels = if self.font.subpixel().is_some() { 3 } else { 1 };
+                    let is_outlined = self.outline_color.is_some() && subpixels == 1;
+                    for (image, outline, _) in images {
+                        let outline = is_outlined.then_some(outline);
+                        for image in iter::once(image).chain(outline) {
+                            let mut image_box = image.bounding_box();
+                            image_box.size.width /= subpixels;
+                            let x = image_box.top_left.x.saturating_add_unsigned(image_box.size.width);
+                            if x > right {
+                                right = x;
+                            }
+                        }
+                    }
+
+                    let shadow_x = self.shadow_offset().map_or(0, |offset| offset.x.max(0));
+                    let next_x = (x as i32).saturating_add(shadow_x);
+                    let next_position = Point::new(next_x, position.y);
+                    let (left, right) = self.x_extend_to_shadow(position.x, right);
+                    let width = right.saturating_sub(left).try_into().unwrap_or_default();
+                    let bounding_box = Rectangle {
+                        top_left: Point::new(left, top),
+                        size: Size::new(width, height),
+                    };
+
+                    TextMetrics { bounding_box, next_position }
+                }
+
+                /// Draws a string as [`draw_string_transparent`](Self::draw_string_transparent)
+                /// does, without rotation or scaling.
+                fn draw_string_transparent_unrotated<D>(
+                    &self,
+                    text: &str,
+                    position: Point,
+                    baseline: Baseline,
+                    target: &mut D,
+                ) -> Result<Point, D::Error>
+                where
+                    T: Blend,
+                    D: ReadPixel<Color = T>,
+                {
+                    let mut right = position.x;
+                    let mut x = position.x as f32;
+                    let metrics = self.font.metrics();
+                    let y = position.y.saturating_add(metrics.y_offset(baseline));
+                    let spacing = self.spacing();
+                    let shadow_offset = self.shadow_offset();
+                    let alpha = Colormap::<u8, $array_length>::alpha();
+                    if let (Some(offset), Some(color)) = (shadow_offset, self.shadow_color) {
+                        let images = images_of_chars(self.font, text, &mut x, y as f32, spacing);
+                        for (image, outline, _) in images {
+                            let is_empty = outline.bounding_box().is_zero_sized();
+                            let image = match self.outline_color {
+                                Some(_) if !is_empty => outline,
+                                _ => image,
+                            };
+                            let image = image.add_offset(offset.x, offset.y);
+                            let image_box = image.bounding_box();
+                            let alphas = image.colors().into_iter().map(|gray| [alpha.get(gray); 3]);
+                            draw_blended(&image_box, alphas, color, target)?;
+
+                            let x = image_box.top_left.x.saturating_add_unsigned(image_box.size.width);
+                            if x > right {
+                                right = x;
+                            }
+                        }
+
+                        x = position.x as f32;
+                    }
+
+                    if let (None, Some(color)) = (self.font.subpixel(), self.outline_color) {
+                        let images = images_of_chars(self.font, text, &mut x, y as f32, spacing);
+                        for (_, image, _) in images {
+                            let image_box = image.bounding_box();
+                            let alphas = image.colors().into_iter().map(|gray| [alpha.get(gray); 3]);
+                            draw_blended(&image_box, alphas, color, target)?;
+
+                            let x = image_box.top_left.x.saturating_add_unsigned(image_box.size.width);
+                            if x > right {
+                                right = x;
+                            }
+                        }
+
+                        x = position.x as f32;
+                    }
+
+                    let color = self.text_color();
+                    let images = images_of_chars(self.font, text, &mut x, y as f32, spacing);
+                    for (image, _, _) in images {
+                        let mut image_box = image.bounding_box();
+                        let mut grays = image.colors().into_iter().map(|gray| alpha.get(gray));
+                        match self.font.subpixel() {
+                            None => {
+                                let alphas = grays.map(|alpha| [alpha; 3]);
+                                draw_blended(&image_box, alphas, color, target)?;
+                            }
+                            Some(order) => {
+                                image_box.size.width /= 3;
+                                let alphas = iter::from_fn(|| {
+                                    let [left, middle, right] =
+                                        [grays.next()?, grays.next()?, grays.next()?];
+
+                                    match order {
+                                        SubpixelOrder::Rgb => Some([left, middle, right]),
+                                        SubpixelOrder::Bgr => Some([right, middle, left]),
+                                    }
+                                });
+                                draw_blended(&image_box, alphas, color, target)?;
+                            }
+                        }
+
+                        let x = image_box.top_left.x.saturating_add_unsigned(image_box.size.width);
+                        if x > right {
+                            right = x;
+                        }
+                    }
+
+                    let shadow_x = shadow_offset.map_or(0, |offset| offset.x.max(0));
+                    let next_x = (x as i32).saturating_add(shadow_x);
+                    let next_position = Point::new(next_x, position.y);
+                    let right = i32::max(x as i32, right);
+                    let width = right.saturating_sub(position.x);
+                    let width = width.try_into().unwrap_or_default();
+                    self.draw_decorations(position.x, y, width, target)?;
+
+                    Ok(next_position)
+                }
+            }
+        )*
+    }
+}
+
+impl_text_renderer! {
     BinaryColor, 2,
     Gray2, 4,
     Gray4, 16,
     Gray8, 256,
 }
 
-fn images_of_chars<'a, C, const N: usize>(
-    charmap: &Charmap<'a, C, N>,
-    text: &str,
-    x: &mut f32,
-    y: f32,
-) -> impl IntoIterator<Item = (Image<ImageRaw<'a, C>>, bool)>
+/// Run of text in vertical writing mode.
+#[derive(Clone, Copy)]
+enum VerticalRun<'a> {
+    /// A glyph cluster that is drawn upright.
+    Upright(&'a str),
+    /// A run of characters that is drawn sideways.
+    Sideways(&'a str),
+    /// A run of digits that is drawn side by side, upright.
+    TateChuYoko(&'a str),
+}
+
+/// Returns `true` if the specified character is drawn upright in vertical writing mode, which is
+/// the case for the characters that have a `Vertical_Orientation` of `U`, `Tu`, or `Tr` in
+/// [UAX #50](https://www.unicode.org/reports/tr50/), such as the CJK scripts and symbols. Those
+/// that have `Tu` or `Tr` are drawn with the vertical alternates that the `vert` feature has
+/// substituted for them. The others, which have `R` and include Latin, Greek, Cyrillic, and most
+/// punctuation, are drawn sideways.
+fn is_upright(char: char) -> bool {
+    matches!(
+        char,
+        '\u{00A7}'
+            | '\u{00A9}'
+            | '\u{00AE}'
+            | '\u{00B1}'
+            | '\u{00BC}'..='\u{00BE}'
+            | '\u{00D7}'
+            | '\u{00F7}'
+            | '\u{02EA}'..='\u{02EB}'
+            | '\u{1100}'..='\u{11FF}'
+            | '\u{1401}'..='\u{167F}'
+            | '\u{18B0}'..='\u{18FF}'
+            | '\u{2016}'
+            | '\u{2020}'..='\u{2021}'
+            | '\u{2030}'..='\u{2031}'
+            | '\u{203B}'..='\u{203C}'
+            | '\u{2042}'
+            | '\u{2047}'..='\u{2049}'
+            | '\u{2051}'
+            | '\u{20DD}'..='\u{20E0}'
+            | '\u{20E2}'..='\u{20E4}'
+            | '\u{2100}'..='\u{2101}'
+            | '\u{2103}'..='\u{2109}'
+            | '\u{210F}'
+            | '\u{2113}'..='\u{2114}'
+            | '\u{2116}'..='\u{2117}'
+            | '\u{211E}'..='\u{2123}'
+            | '\u{2125}'
+            | '\u{2127}'
+            | '\u{2129}'
+            | '\u{212E}'
+            | '\u{2135}'..='\u{213F}'
+            | '\u{2145}'..='\u{214A}'
+            | '\u{214C}'..='\u{214D}'
+            | '\u{214F}'..='\u{2189}'
+            | '\u{218C}'..='\u{218F}'
+            | '\u{221E}'
+            | '\u{2234}'..='\u{2235}'
+            | '\u{2300}'..='\u{2307}'
+            | '\u{230C}'..='\u{231F}'
+            | '\u{2324}'..='\u{232B}'
+            | '\u{237D}'..='\u{239A}'
+            | '\u{23BE}'..='\u{23CD}'
+            | '\u{23CF}'
+            | '\u{23D1}'..='\u{23DB}'
+            | '\u{23E2}'..='\u{2422}'
+            | '\u{2424}'..='\u{24FF}'
+            | '\u{25A0}'..='\u{2767}'
+            | '\u{2776}'..='\u{2793}'
+            | '\u{2B12}'..='\u{2B2F}'
+            | '\u{2B50}'..='\u{2B59}'
+            | '\u{2BB8}'..='\u{2BFF}'
+            | '\u{2E50}'..='\u{2E51}'
+            | '\u{2E80}'..='\u{A4CF}'
+            | '\u{A960}'..='\u{A97F}'
+            | '\u{AC00}'..='\u{D7FF}'
+            | '\u{E000}'..='\u{FAFF}'
+            | '\u{FE10}'..='\u{FE1F}'
+            | '\u{FE30}'..='\u{FE6F}'
+            | '\u{FF00}'..='\u{FF60}'
+            | '\u{FFE0}'..='\u{FFE7}'
+            | '\u{FFF0}'..='\u{FFF8}'
+            | '\u{FFFC}'..='\u{FFFD}'
+            | '\u{13000}'..='\u{1345F}'
+            | '\u{16FE0}'..='\u{18AFF}'
+            | '\u{1B000}'..='\u{1B2FF}'
+            | '\u{1D000}'..='\u{1D1FF}'
+            | '\u{1D2E0}'..='\u{1D37F}'
+            | '\u{1F000}'..='\u{1F64F}'
+            | '\u{1F680}'..='\u{1F7FF}'
+            | '\u{1F900}'..='\u{1FAFF}'
+            | '\u{20000}'..='\u{3FFFD}'
+            | '\u{F0000}'..='\u{10FFFD}'
+    )
+}
+
+/// Splits the specified text into runs for vertical writing mode. Characters that are upright, as
+/// determined by [`is_upright`], are drawn one glyph cluster at a time; runs of the others are
+/// drawn sideways.
+fn vertical_runs<'a, 'b, 's, C, const N: usize, F>(
+    font: &'b F,
+    text: &'s str,
+    tate_chu_yoko: u8,
+) -> impl Iterator<Item = VerticalRun<'s>>
 where
-    C: PixelColor + From<C::Raw>,
+    C: PixelColor + From<C::Raw> + 'a,
+    F: BitmapFontData<'a, C, N>,
     RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
 {
-    let mut chars = text.chars();
-    let mut next_glyph = None;
-    let mut next_entry = None;
-    let mut previous_entry = None;
-    iter::from_fn(move || {
-        let entry = match next_entry {
-            Some(entry) => entry,
-            None => {
-                let slice = chars.as_str();
-                if slice.is_empty() {
-                    *x += previous_entry
-                        .take()
-                        .map(|entry: &CharmapEntry<C, N>| entry.advance_width_to)
-                        .map(|advance_width_to| advance_width_to(Default::default()))
-                        .unwrap_or_default();
+    let digits = move |slice: &str| {
+        let length = slice.bytes().take_while(u8::is_ascii_digit).count();
 
-                    return next_glyph.map(|next: &NextGlyph<C, N>| {
-                        let x = *x + next.x_offset;
-                        let y = y - next.y_offset;
-                        let image = next.glyph.images.get((x * N as f32) as usize);
-                        let image = image.mul_offset(1, -1).add_offset(x as i32, y as i32);
-                        next_glyph = next.glyph.next;
+        (1..=usize::from(tate_chu_yoko))
+            .contains(&length)
+            .then_some(length)
+    };
 
-                        (image, true)
-                    });
-                }
+    let mut text = text;
+    iter::from_fn(move || {
+        let first = text.chars().next()?;
+        let (run, length): (fn(_) -> _, _) = if let Some(length) = digits(text) {
+            (VerticalRun::TateChuYoko, length)
+        } else if is_upright(first) {
+            let (_, count) = font.get_normalized(text);
+            let count = count.max(1);
+            let length = text
+                .char_indices()
+                .nth(count)
+                .map_or(text.len(), |(index, _)| index);
 
-                let entry = charmap.get(slice);
-                *x += previous_entry
-                    .replace(entry)
-                    .map(|entry| entry.advance_width_to)
-                    .map(|advance_width_to| advance_width_to(entry.key))
-                    .unwrap_or_default();
+            (VerticalRun::Upright, length)
+        } else {
+            let mut previous = first;
+            let length = text
+                .char_indices()
+                .skip(1)
+                .find(|&(index, char)| {
+                    let is_run_start = !previous.is_ascii_digit();
+                    previous = char;
 
-                for _ in 0..entry.advance_chars {
-                    let _ = chars.next();
-                }
+                    is_upright(char) || is_run_start && digits(&text[index..]).is_some()
+                })
+                .map_or(text.len(), |(index, _)| index);
 
-                entry
-            }
+            (VerticalRun::Sideways, length)
         };
+        let (slice, rest) = text.split_at(length);
+        text = rest;
 
-        let tuple = match next_glyph {
-            Some(next) => {
-                let x = *x + next.x_offset;
-                let y = y - next.y_offset;
-                let image = next.glyph.images.get((x * N as f32) as usize);
-                let image = image.mul_offset(1, -1).add_offset(x as i32, y as i32);
-                next_glyph = next.glyph.next;
-                next_entry = Some(entry);
+        Some(run(slice))
+    })
+}
 
-                (image, true)
-            }
-            None => {
-                let image = entry.glyph.images.get((*x * N as f32) as usize);
-                let image = image.mul_offset(1, -1).add_offset(*x as i32, y as i32);
-                next_glyph = entry.glyph.next;
-                next_entry = None;
+/// Text that has been truncated, with the parts that are drawn before and after the ellipsis.
+struct Truncated<'s> {
+    /// The part before the ellipsis.
+    head: &'s str,
+    /// The advance width of the part before the ellipsis.
+    head_width: f32,
+    /// The advance width of the ellipsis.
+    ellipsis_width: f32,
+    /// The part after the ellipsis.
+    tail: &'s str,
+}
 
-                (image, false)
-            }
-        };
+/// Settings that change the advance widths of glyph clusters.
+#[derive(Clone, Copy)]
+struct Spacing<'b> {
+    /// The characters that are drawn centered in cells of the same width, and that width.
+    tabular: Option<(&'b str, f32)>,
+    /// The extra space after each glyph cluster.
+    letter_spacing: f32,
+    /// The extra space after each space character.
+    word_spacing: f32,
+}
 
-        Some(tuple)
-    })
+impl Spacing<'_> {
+    /// Returns `true` if the specified charmap entry is for one of the characters that are drawn
+    /// centered in cells of the same width.
+    fn is_tabular<'a, C, const N: usize>(&self, entry: &impl CharmapEntryData<'a, C, N>) -> bool
+    where
+        C: PixelColor + From<C::Raw>,
+        RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+    {
+        let mut key = entry.key().chars();
+        match (self.tabular, key.next(), key.next()) {
+            (Some((chars, _)), Some(char), None) => chars.contains(char),
+            _ => false,
+        }
+    }
+
+    /// Returns the offset along the _x_-axis for the first glyph of the specified charmap entry,
+    /// which centers it in its cell if it is one of the tabular characters.
+    fn x_offset<'a, C, const N: usize>(&self, entry: &impl CharmapEntryData<'a, C, N>) -> f32
+    where
+        C: PixelColor + From<C::Raw>,
+        RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+    {
+        match self.tabular {
+            Some((_, width)) if self.is_tabular(entry) => {
+                (width - entry.advance_width_to(Default::default())) / 2.0
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the value for advancing the position along the _x_-axis for the specified charmap
+    /// entry in order to get to the position of the next charmap entry, if any.
+    fn advance_width<'a, C, const N: usize, E>(&self, entry: E, next: Option<E>) -> f32
+    where
+        C: PixelColor + From<C::Raw>,
+        E: CharmapEntryData<'a, C, N>,
+        RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+    {
+        let advance_width = match self.tabular {
+            Some((_, width)) if self.is_tabular(&entry) => width,
+            Some(_) if next.as_ref().is_some_and(|next| self.is_tabular(next)) => {
+                entry.advance_width_to(Default::default())
+            }
+            _ => entry.advance_width_to(next.map(|next| next.key()).unwrap_or_default()),
+        };
+        let is_space = matches!(entry.key(), " " | "\u{a0}");
+
+        advance_width + self.letter_spacing + if is_space { self.word_spacing } else { 0.0 }
+    }
+}
+
+fn carets_of_chars<'a, C, const N: usize, F>(
+    font: &F,
+    text: &str,
+    x: f32,
+    spacing: Spacing<'_>,
+) -> impl Iterator<Item = (usize, f32)>
+where
+    C: PixelColor + From<C::Raw>,
+    F: BitmapFontData<'a, C, N>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    let clusters = clusters_of_chars(font, text, x, spacing);
+    let carets = clusters.map(|(index, x, _)| (index, x));
+
+    iter::once((0, x)).chain(carets)
+}
+
+/// Returns the byte index at the end of each glyph cluster in the specified text, along with the
+/// position of the next glyph cluster and the position that the text would end at if it were cut
+/// after the glyph cluster, which does not include kerning with the next one.
+fn clusters_of_chars<'a, C, const N: usize, F>(
+    font: &F,
+    text: &str,
+    x: f32,
+    spacing: Spacing,
+) -> impl Iterator<Item = (usize, f32, f32)>
+where
+    C: PixelColor + From<C::Raw>,
+    F: BitmapFontData<'a, C, N>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    let mut x = x;
+    let mut index = 0;
+    let mut entry = (!text.is_empty()).then(|| font.get_normalized(text));
+    iter::from_fn(move || {
+        let (current, count) = entry.take()?;
+        let slice = &text[index..];
+        index += slice
+            .char_indices()
+            .nth(count.max(1))
+            .map_or(slice.len(), |(index, _)| index);
+
+        let slice = &text[index..];
+        let next = (!slice.is_empty()).then(|| font.get_normalized(slice));
+        let end_x = x + spacing.advance_width(current, None);
+        x += spacing.advance_width(current, next.map(|(next, _)| next));
+        entry = next;
+
+        Some((index, x, end_x))
+    })
+}
+
+fn images_of_chars<'a, 'b, C, const N: usize, F>(
+    font: &'b F,
+    text: &str,
+    x: &mut f32,
+    y: f32,
+    spacing: Spacing<'_>,
+) -> impl IntoIterator<Item = (Image<ImageData<'a, C>>, Image<ImageData<'a, C>>, bool)>
+where
+    C: PixelColor + From<C::Raw> + 'a,
+    F: BitmapFontData<'a, C, N>,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    let mut chars = text.chars();
+    let mut next_glyph: Option<(F::Entry<'b>, usize)> = None;
+    let mut next_entry = None;
+    let mut previous_entry: Option<F::Entry<'b>> = None;
+    iter::from_fn(move || {
+        let entry = match next_entry {
+            Some(entry) => entry,
+            None => {
+                let slice = chars.as_str();
+                if slice.is_empty() {
+                    *x += previous_entry
+                        .take()
+                        .map(|entry| spacing.advance_width(entry, None))
+                        .unwrap_or_default();
+
+                    let (entry, index) = next_glyph?;
+                    let (_, x_offset, y_offset) = entry.glyph(index)?;
+                    let [image, outline] = glyph_images(entry, index, *x + x_offset, y - y_offset);
+                    next_glyph = entry.glyph(index + 1).map(|_| (entry, index + 1));
+
+                    return Some((image, outline, true));
+                }
+
+                let (entry, count) = font.get_normalized(slice);
+                *x += previous_entry
+                    .replace(entry)
+                    .map(|previous_entry| spacing.advance_width(previous_entry, Some(entry)))
+                    .unwrap_or_default();
+
+                for _ in 0..count {
+                    let _ = chars.next();
+                }
+
+                entry
+            }
+        };
+
+        let tuple = match next_glyph {
+            Some((next, index)) => {
+                let (_, x_offset, y_offset) = next.glyph(index).unwrap_or_default();
+                let [image, outline] = glyph_images(next, index, *x + x_offset, y - y_offset);
+                next_glyph = next.glyph(index + 1).map(|_| (next, index + 1));
+                next_entry = Some(entry);
+
+                (image, outline, true)
+            }
+            None => {
+                let [image, outline] = glyph_images(entry, 0, *x + spacing.x_offset(&entry), y);
+                next_glyph = entry.glyph(1).map(|_| (entry, 1));
+                next_entry = None;
+
+                (image, outline, false)
+            }
+        };
+
+        Some(tuple)
+    })
+}
+
+fn glyph_images<'a, C, const N: usize>(
+    entry: impl CharmapEntryData<'a, C, N>,
+    index: usize,
+    x: f32,
+    y: f32,
+) -> [Image<ImageData<'a, C>>; 2]
+where
+    C: PixelColor + From<C::Raw> + 'a,
+    RawDataSlice<'a, C::Raw, BigEndian>: IntoIterator<Item = C::Raw>,
+{
+    let position = (x * N as f32) as usize;
+    let images = [
+        entry.image(index, position),
+        entry.outline_image(index, position),
+    ];
+
+    images.map(|image| image.mul_offset(1, -1).add_offset(x as i32, y as i32))
+}
+
+fn draw_images<U, D>(
+    images: impl IntoIterator<Item = (Image<U>, bool)>,
+    line_strip: Rectangle,
+    background_style: &PrimitiveStyle<D::Color>,
+    target: &mut D,
+    mut draw: impl FnMut(&Image<U>, &Rectangle, &mut D) -> Result<(), D::Error>,
+    mut mix: impl FnMut(&Image<U>, &Image<U>, &mut D) -> Result<(), D::Error>,
+) -> Result<i32, D::Error>
+where
+    U: ImageDrawable,
+    D: DrawTarget,
+{
+    let mut right = line_strip.top_left.x;
+    let top = line_strip.top_left.y;
+    let bottom = top.saturating_add_unsigned(line_strip.size.height);
+    let mut image_before_overlays: Option<Image<_>> = None;
+    let mut previous_image: Option<Image<_>> = None;
+    let mut previous_right = right;
+    for (image, is_overlay) in images {
+        let image_box = image.bounding_box();
+        let x = image_box
+            .top_left
+            .x
+            .saturating_add_unsigned(image_box.size.width);
+        if x > right {
+            right = x;
+        }
+
+        if !is_overlay && image_before_overlays.is_some() {
+            previous_image = image_before_overlays.take();
+        }
+
+        let line_piece = line_strip.left_of(&image_box);
+        let clip_area = if let Some(previous_image) = previous_image.as_ref() {
+            let previous_image_box = previous_image.bounding_box();
+            let previous_right_half = previous_image_box.indent_to(previous_right);
+            let line_piece = line_piece.right_of(&previous_right_half);
+            line_piece.draw_styled(background_style, target)?;
+
+            let left = previous_right_half.left_of(&image_box);
+            let left = left.y_extend(top, bottom);
+            let right = previous_right_half.right_of(&image_box);
+            let right = right.y_extend(top, bottom);
+            let middle = previous_right_half.left_of(&right).right_of(&left);
+            let middle = middle.y_extend(top, bottom);
+            let above = middle.above(&image_box);
+            let below = middle.below(&image_box);
+            for clip_area in [left, right, above, below] {
+                draw(previous_image, &clip_area, target)?;
+
+                let above = clip_area.above(&previous_image_box);
+                let below = clip_area.below(&previous_image_box);
+                for fill_area in [above, below] {
+                    fill_area.draw_styled(background_style, target)?;
+                }
+            }
+
+            let image_box = if is_overlay {
+                let image_box = image_box.y_reduce(top, bottom);
+                let clip_area = image_box.left_of(&previous_image_box);
+                draw(&image, &clip_area, target)?;
+
+                image_box
+            } else {
+                image_box.left_half()
+            };
+
+            let column = previous_image_box.y_extend(top, bottom);
+            let above = column.above(&previous_image_box);
+            let below = column.below(&previous_image_box);
+            for clip_area in [above, below] {
+                draw(&image, &clip_area, target)?;
+            }
+
+            mix(&image, previous_image, target)?;
+
+            image_box.right_of(&previous_image_box)
+        } else {
+            line_piece.draw_styled(background_style, target)?;
+
+            image_box.left_half()
+        };
+        draw(&image, &clip_area, target)?;
+
+        let right = clip_area.indent_to(previous_right);
+        let column = right.y_extend(top, bottom);
+        let above = column.above(&image_box);
+        let below = column.below(&image_box);
+        for fill_area in [above, below] {
+            fill_area.draw_styled(background_style, target)?;
+        }
+
+        let previous_image = previous_image.replace(image);
+        if is_overlay && image_before_overlays.is_none() {
+            image_before_overlays = previous_image;
+        }
+
+        previous_right = right.top_left.x.saturating_add_unsigned(right.size.width);
+    }
+
+    if let Some(previous_image) = previous_image.take() {
+        let previous_image_box = previous_image.bounding_box();
+        let previous_right_half = previous_image_box.indent_to(previous_right);
+        draw(&previous_image, &previous_right_half, target)?;
+
+        let column = previous_right_half.y_extend(top, bottom);
+        let above = column.above(&previous_image_box);
+        let below = column.below(&previous_image_box);
+        for fill_area in [above, below] {
+            fill_area.draw_styled(background_style, target)?;
+        }
+    }
+
+    Ok(right)
+}
+
+fn draw_mixed<U, T, D, const N: usize>(
+    image: &Image<U>,
+    other: &Image<U>,
+    colormap: &Colormap<T, N>,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    U: ImageDrawable<Color = T> + Colors<T>,
+    T: PixelColor + Default + Invert + Screen,
+    D: DrawTarget<Color = T>,
+{
+    let area = image.bounding_box().intersection(&other.bounding_box());
+    let first = image.clipped(&area);
+    let second = other.clipped(&area);
+    let start = colormap.first();
+    let end = colormap.last();
+    if start == end {
+        target.fill_solid(&area, start)
+    } else {
+        let colors = first
+            .colors()
+            .into_iter()
+            .zip(second.colors())
+            .map(|(first, second)| first.screen(second, start, end));
+
+        target.fill_contiguous(&area, colors)
+    }
+}
+
+/// Blends the specified color over the pixels in the specified area that are already on the draw
+/// target, with the opacities of the red, green, and blue channels of each pixel, in row-major
+/// order. Pixels that are fully transparent are not drawn; the others are drawn in runs of up to
+/// [`BLEND_BUFFER_LENGTH`] pixels.
+fn draw_blended<T, D>(
+    area: &Rectangle,
+    alphas: impl IntoIterator<Item = [u8; 3]>,
+    color: T,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    T: PixelColor + Default + Screen + Blend,
+    D: ReadPixel<Color = T>,
+{
+    let mut alphas = alphas.into_iter();
+    let mut buffer = [T::default(); BLEND_BUFFER_LENGTH];
+    for y in area.rows() {
+        let mut left = area.top_left.x;
+        let mut length = 0;
+        for x in area.columns() {
+            let alpha = alphas.next().unwrap_or_default();
+            if alpha == [0; 3] || length == BLEND_BUFFER_LENGTH {
+                draw_run(Point::new(left, y), &buffer[..length], target)?;
+                left = x;
+                length = 0;
+            }
+
+            if alpha == [0; 3] {
+                left = x.saturating_add(1);
+                continue;
+            }
+
+            let pixel_color = target.read_pixel(Point::new(x, y)).unwrap_or_default();
+            buffer[length] = match alpha {
+                [red, green, blue] if red == green && green == blue => {
+                    pixel_color.blend(color, green)
+                }
+                [red, green, blue] => T::subpixel(
+                    pixel_color.blend(color, red),
+                    pixel_color.blend(color, green),
+                    pixel_color.blend(color, blue),
+                ),
+            };
+            length += 1;
+        }
+
+        draw_run(Point::new(left, y), &buffer[..length], target)?;
+    }
+
+    Ok(())
+}
+
+/// Draws the specified colors in a single row, starting at the specified point.
+fn draw_run<T, D>(point: Point, colors: &[T], target: &mut D) -> Result<(), D::Error>
+where
+    T: PixelColor,
+    D: DrawTarget<Color = T>,
+{
+    if colors.is_empty() {
+        return Ok(());
+    }
+
+    let area = Rectangle::new(point, Size::new(colors.len() as u32, 1));
+
+    target.fill_contiguous(&area, colors.iter().copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::ToOwned;
+    use std::string::String;
+    use std::vec::Vec;
+
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb888;
+    use embedded_graphics::prelude::*;
+    use embedded_graphics::text::Text;
+
+    use super::*;
+    use crate::mplus;
+
+    #[test]
+    fn draw_text_with_subpixel_order() {
+        let bitmap_font = mplus!(font = 2, size = 16, sources = [["Hello"]]);
+        let rgb_font = mplus!(font = 2, size = 16, subpixel = rgb, sources = [["Hello"]]);
+        let bgr_font = mplus!(font = 2, size = 16, subpixel = bgr, sources = [["Hello"]]);
+        let position = Point::new(0, 20);
+
+        let mut display = MockDisplay::new();
+        let character_style = BitmapFontStyle::new(&bitmap_font, Rgb888::WHITE);
+        let text = Text::new("Hello", position, character_style);
+        let next_position = text.draw(&mut display).unwrap();
+        let bounding_box = text.bounding_box();
+
+        let mut rgb_display = MockDisplay::new();
+        let character_style = BitmapFontStyle::new(&rgb_font, Rgb888::WHITE);
+        let text = Text::new("Hello", position, character_style);
+        assert_eq!(text.draw(&mut rgb_display).unwrap(), next_position);
+        assert!(
+            text.bounding_box()
+                .size
+                .width
+                .abs_diff(bounding_box.size.width)
+                <= 1
+        );
+
+        let mut bgr_display = MockDisplay::new();
+        let character_style = BitmapFontStyle::new(&bgr_font, Rgb888::WHITE);
+        let text = Text::new("Hello", position, character_style);
+        assert_eq!(text.draw(&mut bgr_display).unwrap(), next_position);
+        assert_ne!(rgb_display, display);
+        assert_ne!(rgb_display, bgr_display);
+
+        let points = bounding_box.points();
+        assert!(points.into_iter().any(|point| {
+            rgb_display
+                .get_pixel(point)
+                .is_some_and(|color| color.r() != color.b())
+        }));
+        assert_eq!(
+            rgb_display.map(|color| Rgb888::new(color.b(), color.g(), color.r())),
+            bgr_display
+        );
+    }
+
+    macro_rules! test_draw_text_transparent {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $text:expr, $text_color:expr, $background_color:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let text_color = $text_color;
+                    let background_color = $background_color;
+                    let position = Point::new(2, 30);
+
+                    let mut expected = MockDisplay::new();
+                    expected.set_allow_overdraw(true);
+                    expected.clear(background_color).unwrap();
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(text_color)
+                        .background_color(background_color)
+                        .font(&bitmap_font)
+                        .build();
+                    let text = Text::new($text, position, character_style);
+                    let next_position = text.draw(&mut expected).unwrap();
+
+                    let mut display = MockDisplay::new();
+                    display.set_allow_overdraw(true);
+                    display.clear(background_color).unwrap();
+                    let character_style = BitmapFontStyle::new(&bitmap_font, text_color);
+                    let result = character_style
+                        .draw_string_transparent($text, position, Baseline::Alphabetic, &mut display)
+                        .unwrap();
+                    assert_eq!(result, next_position);
+
+                    let mut gradient = MockDisplay::new();
+                    gradient.set_allow_overdraw(true);
+                    let bounding_box = gradient.bounding_box();
+                    let colors = bounding_box.points().map(|Point { x, y }| {
+                        Rgb888::new(4 * x as u8, 4 * y as u8, 255 - 4 * x as u8)
+                    });
+                    gradient.fill_contiguous(&bounding_box, colors.clone()).unwrap();
+                    character_style
+                        .draw_string_transparent($text, position, Baseline::Alphabetic, &mut gradient)
+                        .unwrap();
+
+                    for (point, color) in bounding_box.points().zip(colors) {
+                        let expected = expected.get_pixel(point).unwrap();
+                        let result = display.get_pixel(point).unwrap();
+                        assert!(
+                            expected.r().abs_diff(result.r()) <= 1
+                                && expected.g().abs_diff(result.g()) <= 1
+                                && expected.b().abs_diff(result.b()) <= 1,
+                            "\n point: {point:?}, expected: {expected:?}, result: {result:?}"
+                        );
+
+                        if expected == background_color {
+                            assert_eq!(gradient.get_pixel(point), Some(color), "\n point: {point:?}");
+                        }
+                    }
+                }
+            )*
+        }
+    }
+
+    test_draw_text_transparent! {
+        draw_text_transparent_1_400_16_false_1_4_over_blue,
+            mplus!(font = 1, size = 16, sources = [["Hi, AV!"]]),
+            "Hi, AV!",
+            Rgb888::new(250, 200, 10),
+            Rgb888::new(40, 80, 120),
+
+        draw_text_transparent_2_700_20_true_4_8_over_white,
+            mplus!(font = 2, weight = 700, size = 20, hint = true, positions = 4, bit_depth = 8, sources = [["Wave"]]),
+            "Wave",
+            Rgb888::new(0, 0, 0),
+            Rgb888::new(255, 255, 255),
+
+        draw_text_transparent_code_100_400_16_false_1_2_subpixel,
+            mplus!(font = code(100), size = 16, bit_depth = 2, subpixel = rgb, sources = [["mono"]]),
+            "mono",
+            Rgb888::new(255, 255, 255),
+            Rgb888::new(0, 0, 0),
+    }
+
+    macro_rules! test_draw_text_with_outline {
+        (
+            $(
+                $fn_ident:ident,
+                $bitmap_font:expr,
+                $outlined_font:expr,
+                $text:expr,
+                $text_color:expr,
+                $outline_color:expr,
+                $background_color:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let outlined_font = $outlined_font;
+                    let text_color = $text_color;
+                    let outline_color = $outline_color;
+                    let background_color = $background_color;
+                    let position = Point::new(2, 30);
+
+                    let mut expected = MockDisplay::new();
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(text_color)
+                        .background_color(background_color)
+                        .font(&bitmap_font)
+                        .build();
+                    let text = Text::new($text, position, character_style);
+                    let next_position = text.draw(&mut expected).unwrap();
+
+                    let mut display = MockDisplay::new();
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(text_color)
+                        .background_color(background_color)
+                        .font(&outlined_font)
+                        .build();
+                    let text = Text::new($text, position, character_style);
+                    assert_eq!(text.draw(&mut display).unwrap(), next_position);
+                    assert_eq!(display, expected);
+
+                    let mut display = MockDisplay::new();
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(text_color)
+                        .background_color(background_color)
+                        .outline_color(outline_color)
+                        .font(&outlined_font)
+                        .build();
+                    let text = Text::new($text, position, character_style.clone());
+                    assert_eq!(text.draw(&mut display).unwrap(), next_position);
+
+                    let mut transparent = MockDisplay::new();
+                    transparent.set_allow_overdraw(true);
+                    transparent.clear(background_color).unwrap();
+                    let result = character_style
+                        .draw_string_transparent($text, position, Baseline::Alphabetic, &mut transparent)
+                        .unwrap();
+                    assert_eq!(result, next_position);
+
+                    let bounding_box = display.affected_area();
+                    let mut colors = Vec::new();
+                    for point in bounding_box.points() {
+                        let Some(result) = display.get_pixel(point) else {
+                            continue;
+                        };
+
+                        let expected = transparent.get_pixel(point).unwrap();
+                        assert!(
+                            expected.r().abs_diff(result.r()) <= 2
+                                && expected.g().abs_diff(result.g()) <= 2
+                                && expected.b().abs_diff(result.b()) <= 2,
+                            "\n point: {point:?}, expected: {expected:?}, result: {result:?}"
+                        );
+
+                        colors.push(result);
+                    }
+
+                    assert!(colors.contains(&text_color));
+                    assert!(colors.contains(&outline_color));
+                    assert!(colors.contains(&background_color));
+                }
+            )*
+        }
+    }
+
+    test_draw_text_with_outline! {
+        draw_text_with_outline_1_400_16_false_1_4_over_blue,
+            mplus!(font = 1, size = 16, sources = [["Hi, AV!"]]),
+            mplus!(font = 1, size = 16, outline = 1, sources = [["Hi, AV!"]]),
+            "Hi, AV!",
+            Rgb888::new(255, 255, 255),
+            Rgb888::new(0, 0, 0),
+            Rgb888::new(40, 80, 120),
+
+        draw_text_with_outline_2_700_20_true_4_8_over_white,
+            mplus!(font = 2, weight = 700, size = 20, hint = true, positions = 4, bit_depth = 8, sources = [["Wave"]]),
+            mplus!(font = 2, weight = 700, size = 20, hint = true, positions = 4, bit_depth = 8, outline = 2, sources = [["Wave"]]),
+            "Wave",
+            Rgb888::new(250, 200, 10),
+            Rgb888::new(20, 0, 60),
+            Rgb888::new(255, 255, 255),
+    }
+
+    macro_rules! test_draw_text_with_shadow {
+        (
+            $(
+                $fn_ident:ident,
+                $bitmap_font:expr,
+                $text:expr,
+                $text_color:expr,
+                $outline_color:expr,
+                $shadow_color:expr,
+                $background_color:expr,
+                $shadow_offset:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let text_color = $text_color;
+                    let shadow_color = $shadow_color;
+                    let background_color = $background_color;
+                    let Point { x: dx, y: dy } = $shadow_offset;
+                    let position = Point::new(4, 30);
+
+                    let builder = BitmapFontStyleBuilder::new()
+                        .text_color(text_color)
+                        .background_color(background_color)
+                        .font(&bitmap_font);
+                    let builder = match $outline_color {
+                        Some(outline_color) => builder.outline_color(outline_color),
+                        None => builder,
+                    };
+                    let plain_style = builder.clone().build();
+                    let builder = builder.shadow(shadow_color, dx, dy);
+                    let character_style = builder.clone().build();
+                    let expected = plain_style.measure_string($text, position, Baseline::Alphabetic);
+                    let metrics = character_style.measure_string($text, position, Baseline::Alphabetic);
+                    let next_x = expected.next_position.x + dx.max(0);
+                    assert_eq!(metrics.next_position, Point::new(next_x, position.y));
+                    assert_eq!(metrics.bounding_box.top_left.x, expected.bounding_box.top_left.x + dx.min(0));
+                    assert_eq!(metrics.bounding_box.top_left.y, expected.bounding_box.top_left.y + dy.min(0));
+                    assert_eq!(metrics.bounding_box.size.height, expected.bounding_box.size.height + dy.unsigned_abs());
+                    assert!(metrics.bounding_box.size.width >= expected.bounding_box.size.width);
+                    assert_eq!(character_style.line_height(), plain_style.line_height() + dy.unsigned_abs());
+                    assert_eq!(builder.reset_shadow().build().line_height(), plain_style.line_height());
+
+                    let mut display = MockDisplay::new();
+                    let text = Text::new($text, position, character_style.clone());
+                    assert_eq!(text.draw(&mut display).unwrap(), metrics.next_position);
+
+                    let bounding_box = display.affected_area();
+                    assert_eq!(bounding_box.top_left.y, metrics.bounding_box.top_left.y);
+                    assert!(display.get_pixel(metrics.bounding_box.top_left).is_some());
+                    assert_eq!(bounding_box.size.height, metrics.bounding_box.size.height);
+
+                    let mut transparent = MockDisplay::new();
+                    transparent.set_allow_overdraw(true);
+                    transparent.clear(background_color).unwrap();
+                    let result = character_style
+                        .draw_string_transparent($text, position, Baseline::Alphabetic, &mut transparent)
+                        .unwrap();
+                    assert_eq!(result, metrics.next_position);
+
+                    let mut colors = Vec::new();
+                    for point in bounding_box.points() {
+                        let Some(result) = display.get_pixel(point) else {
+                            continue;
+                        };
+
+                        let expected = transparent.get_pixel(point).unwrap();
+                        assert!(
+                            expected.r().abs_diff(result.r()) <= 2
+                                && expected.g().abs_diff(result.g()) <= 2
+                                && expected.b().abs_diff(result.b()) <= 2,
+                            "\n point: {point:?}, expected: {expected:?}, result: {result:?}"
+                        );
+
+                        colors.push(result);
+                    }
+
+                    assert!(colors.contains(&text_color));
+                    assert!(colors.contains(&shadow_color));
+                    assert!(colors.contains(&background_color));
+                }
+            )*
+        }
+    }
+
+    test_draw_text_with_shadow! {
+        draw_text_with_shadow_1_400_16_false_1_4_down_right,
+            mplus!(font = 1, size = 16, sources = [["Hi, AV!"]]),
+            "Hi, AV!",
+            Rgb888::new(255, 255, 255),
+            None::<Rgb888>,
+            Rgb888::new(0, 0, 0),
+            Rgb888::new(40, 80, 120),
+            Point::new(2, 3),
+
+        draw_text_with_shadow_2_700_20_true_4_8_outline_2_up_left,
+            mplus!(font = 2, weight = 700, size = 20, hint = true, positions = 4, bit_depth = 8, outline = 2, sources = [["Wave"]]),
+            "Wave",
+            Rgb888::new(250, 200, 10),
+            Some(Rgb888::new(20, 0, 60)),
+            Rgb888::new(0, 40, 0),
+            Rgb888::new(255, 255, 255),
+            Point::new(-1, -2),
+
+        draw_text_with_shadow_1_400_16_false_1_4_left,
+            mplus!(font = 1, size = 16, sources = [["Hi, AV!"]]),
+            "Hi, AV!",
+            Rgb888::new(255, 255, 255),
+            None::<Rgb888>,
+            Rgb888::new(0, 0, 0),
+            Rgb888::new(40, 80, 120),
+            Point::new(-3, 1),
+    }
+
+    fn rotate_rectangle(rectangle: Rectangle, origin: Point, rotation: Rotation) -> Rectangle {
+        let Some(bottom_right) = rectangle.bottom_right() else {
+            panic!("expected non-zero-sized rectangle");
+        };
+
+        Rectangle::with_corners(
+            rotation.rotate_about(rectangle.top_left, origin),
+            rotation.rotate_about(bottom_right, origin),
+        )
+    }
+
+    macro_rules! test_draw_text_rotated {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $text:expr, $rotation:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let rotation = $rotation;
+                    let position = Point::new(32, 32);
+                    let builder = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::new(250, 200, 10))
+                        .background_color(Rgb888::new(40, 80, 120))
+                        .underline_with_color(Rgb888::new(255, 0, 255))
+                        .font(&bitmap_font);
+
+                    let character_style = builder.clone().build();
+                    let metrics = character_style.measure_string($text, position, Baseline::Middle);
+                    let mut expected = MockDisplay::new();
+                    expected.set_allow_overdraw(true);
+                    let text = Text::with_baseline($text, position, character_style, Baseline::Middle);
+                    let next_position = text.draw(&mut expected).unwrap();
+
+                    let mut transparent_expected = MockDisplay::new();
+                    transparent_expected.set_allow_overdraw(true);
+                    transparent_expected.clear(Rgb888::new(40, 80, 120)).unwrap();
+                    builder
+                        .clone()
+                        .build()
+                        .draw_string_transparent($text, position, Baseline::Middle, &mut transparent_expected)
+                        .unwrap();
+
+                    let character_style = builder.rotation(rotation).build();
+                    let result = character_style.measure_string($text, position, Baseline::Middle);
+                    assert_eq!(result.bounding_box, rotate_rectangle(metrics.bounding_box, position, rotation));
+                    assert_eq!(result.next_position, rotation.rotate_about(metrics.next_position, position));
+
+                    let mut display = MockDisplay::new();
+                    display.set_allow_overdraw(true);
+                    let text = Text::with_baseline($text, position, character_style.clone(), Baseline::Middle);
+                    let result = text.draw(&mut display).unwrap();
+                    assert_eq!(result, rotation.rotate_about(next_position, position));
+
+                    let mut transparent = MockDisplay::new();
+                    transparent.set_allow_overdraw(true);
+                    transparent.clear(Rgb888::new(40, 80, 120)).unwrap();
+                    let result = character_style
+                        .draw_string_transparent($text, position, Baseline::Middle, &mut transparent)
+                        .unwrap();
+                    assert_eq!(result, rotation.rotate_about(next_position, position));
+
+                    let bounding_box = expected.affected_area();
+                    assert_eq!(display.affected_area(), rotate_rectangle(bounding_box, position, rotation));
+                    for point in bounding_box.points() {
+                        let rotated_point = rotation.rotate_about(point, position);
+                        assert_eq!(
+                            display.get_pixel(rotated_point),
+                            expected.get_pixel(point),
+                            "\n point: {point:?}"
+                        );
+                        assert_eq!(
+                            transparent.get_pixel(rotated_point),
+                            transparent_expected.get_pixel(point),
+                            "\n point: {point:?}"
+                        );
+                    }
+                }
+            )*
+        }
+    }
+
+    test_draw_text_rotated! {
+        draw_text_rotated_1_400_12_false_1_4_by_0,
+            mplus!(font = 1, size = 12, sources = [["Hi!"]]),
+            "Hi!",
+            Rotation::Deg0,
+
+        draw_text_rotated_1_400_12_false_1_4_by_90,
+            mplus!(font = 1, size = 12, sources = [["Hi!"]]),
+            "Hi!",
+            Rotation::Deg90,
+
+        draw_text_rotated_2_700_14_true_4_8_by_180,
+            mplus!(font = 2, weight = 700, size = 14, hint = true, positions = 4, bit_depth = 8, sources = [["AVa"]]),
+            "AVa",
+            Rotation::Deg180,
+
+        draw_text_rotated_code_100_400_12_false_1_4_by_270,
+            mplus!(font = code(100), size = 12, sources = [["0x1F"]]),
+            "0x1F",
+            Rotation::Deg270,
+    }
+
+    macro_rules! test_draw_text_vertical {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $horizontal_font:expr, $text:expr, $punctuation:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let horizontal_font = $horizontal_font;
+                    let mut advance_height = 0.0;
+                    for c in $text.chars() {
+                        let slice = c.encode_utf8(&mut [0; 4]).to_owned();
+                        let entry = bitmap_font.get(&slice);
+                        assert!(entry.advance_height() > 0.0, "\n slice: {slice:?}");
+                        assert_eq!(horizontal_font.get(&slice).advance_height(), 0.0);
+                        advance_height += entry.advance_height();
+                    }
+
+                    let image_box = bitmap_font.get($punctuation).image(0, 0).bounding_box();
+                    let horizontal_box = horizontal_font.get($punctuation).image(0, 0).bounding_box();
+                    assert!(image_box.top_left.x > horizontal_box.top_left.x);
+                    assert!(image_box.top_left.y > horizontal_box.top_left.y);
+
+                    let position = Point::new(32, 4);
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::new(250, 200, 10))
+                        .background_color(Rgb888::new(40, 80, 120))
+                        .underline()
+                        .font(&bitmap_font)
+                        .build();
+
+                    let metrics = character_style.measure_string_vertical($text, position);
+                    assert_eq!(metrics.next_position, Point::new(32, 4 + advance_height as i32));
+                    assert_eq!(metrics.bounding_box.size.width, character_style.line_height());
+
+                    let mut display = MockDisplay::new();
+                    let result = character_style
+                        .draw_string_vertical($text, position, &mut display)
+                        .unwrap();
+                    assert_eq!(result, metrics.next_position);
+                    assert_eq!(display.affected_area(), metrics.bounding_box);
+                }
+            )*
+        }
+    }
+
+    test_draw_text_vertical! {
+        draw_text_vertical_1_400_12_false_1_4,
+            mplus!(font = 1, size = 12, vertical = true, sources = [["日本、"]]),
+            mplus!(font = 1, size = 12, sources = [["日本、"]]),
+            "日本、",
+            "、",
+
+        draw_text_vertical_2_700_16_true_4_8,
+            mplus!(font = 2, weight = 700, size = 16, hint = true, positions = 4, bit_depth = 8, vertical = true, sources = [["縦」。"]]),
+            mplus!(font = 2, weight = 700, size = 16, hint = true, positions = 4, bit_depth = 8, sources = [["縦」。"]]),
+            "縦」。",
+            "。",
+    }
+
+    macro_rules! test_draw_text_vertical_sideways {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $text:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let metrics = bitmap_font.metrics();
+                    let central_offset = ((metrics.ascender + metrics.descender) / 2.0) as i32;
+                    let origin = Point::new(32, 4);
+                    let position = origin + Point::new(0, central_offset);
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::new(250, 200, 10))
+                        .background_color(Rgb888::new(40, 80, 120))
+                        .font(&bitmap_font)
+                        .build();
+
+                    let mut expected = MockDisplay::new();
+                    expected.set_allow_out_of_bounds_drawing(true);
+                    let text = Text::with_baseline($text, position, character_style.clone(), Baseline::Alphabetic);
+                    let next_position = text.draw(&mut expected).unwrap();
+
+                    let metrics = character_style.measure_string_vertical($text, origin);
+                    assert_eq!(metrics.next_position, Point::new(32, 4 + next_position.x - 32));
+
+                    let mut display = MockDisplay::new();
+                    let result = character_style
+                        .draw_string_vertical($text, origin, &mut display)
+                        .unwrap();
+                    assert_eq!(result, metrics.next_position);
+                    assert_eq!(display.affected_area(), metrics.bounding_box);
+
+                    let bounding_box = expected.affected_area();
+                    for point in bounding_box.points() {
+                        let rotated_point = Rotation::Deg90.rotate_about(point, origin);
+                        assert_eq!(
+                            display.get_pixel(rotated_point),
+                            expected.get_pixel(point),
+                            "\n point: {point:?}"
+                        );
+                    }
+                }
+            )*
+        }
+    }
+
+    test_draw_text_vertical_sideways! {
+        draw_text_vertical_sideways_1_400_12_false_1_4,
+            mplus!(font = 1, size = 12, vertical = true, sources = [["Hi!"]]),
+            "Hi!",
+
+        draw_text_vertical_sideways_code_100_400_12_false_1_4,
+            mplus!(font = code(100), size = 12, vertical = true, sources = [["0x1F"]]),
+            "0x1F",
+
+        draw_text_vertical_sideways_1_400_12_false_1_4_diacritics,
+            mplus!(font = 1, size = 12, vertical = true, sources = [["Café Tiễn"]]),
+            "Café Tiễn",
+
+        draw_text_vertical_sideways_2_400_12_false_1_4_en_dash,
+            mplus!(font = 2, size = 12, vertical = true, sources = [["A–Z"]]),
+            "A–Z",
+    }
+
+    macro_rules! test_draw_text_tate_chu_yoko {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $text:expr, $digits:expr, $tate_chu_yoko:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let metrics = bitmap_font.metrics();
+                    let em = metrics.ascender - metrics.descender;
+                    let position = Point::new(32, 4);
+                    let builder = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::new(250, 200, 10))
+                        .background_color(Rgb888::new(40, 80, 120))
+                        .font(&bitmap_font);
+
+                    let character_style = builder.clone().build();
+                    let sideways = character_style.measure_string($digits, Point::zero(), Baseline::Alphabetic);
+                    let metrics = character_style.measure_string_vertical($text, position);
+                    let mut advance_height = 0.0;
+                    for c in $text.chars().filter(|c| !c.is_ascii_digit()) {
+                        let slice = c.encode_utf8(&mut [0; 4]).to_owned();
+                        advance_height += bitmap_font.get(&slice).advance_height();
+                    }
+
+                    let expected = 4.0 + advance_height + sideways.next_position.x as f32;
+                    assert_eq!(metrics.next_position.y, expected as i32);
+
+                    let character_style = builder.clone().tate_chu_yoko($tate_chu_yoko).build();
+                    let tate_chu_yoko_metrics = character_style.measure_string_vertical($text, position);
+                    let expected = 4.0 + advance_height + em;
+                    assert_eq!(tate_chu_yoko_metrics.next_position.y, expected as i32);
+
+                    let mut display = MockDisplay::new();
+                    let result = character_style
+                        .draw_string_vertical($text, position, &mut display)
+                        .unwrap();
+                    assert_eq!(result, tate_chu_yoko_metrics.next_position);
+                    assert_eq!(display.affected_area(), tate_chu_yoko_metrics.bounding_box);
+
+                    let character_style = builder.tate_chu_yoko($tate_chu_yoko - 1).build();
+                    let result = character_style.measure_string_vertical($text, position);
+                    assert_eq!(result, metrics);
+                }
+            )*
+        }
+    }
+
+    test_draw_text_tate_chu_yoko! {
+        draw_text_tate_chu_yoko_1_400_12_false_1_4_two_digits,
+            mplus!(font = 1, size = 12, vertical = true, sources = [["第12回"]]),
+            "第12回",
+            "12",
+            2,
+
+        draw_text_tate_chu_yoko_2_400_14_false_2_4_three_digits,
+            mplus!(font = 2, size = 14, positions = 2, vertical = true, sources = [["令和100年"]]),
+            "令和100年",
+            "100",
+            3,
+    }
+
+    macro_rules! test_draw_text_tabular {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $tabular_chars:expr, $texts:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let advance_widths = $tabular_chars.chars().map(|c| {
+                        let slice = c.encode_utf8(&mut [0; 4]).to_owned();
+
+                        (c, bitmap_font.get(&slice).advance_width_to(""))
+                    });
+                    let advance_widths: Vec<_> = advance_widths.collect();
+                    let width = advance_widths.iter().map(|(_, value)| *value).fold(0.0, f32::max);
+                    let position = Point::new(2, 32);
+                    let builder = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::WHITE)
+                        .background_color(Rgb888::BLACK)
+                        .font(&bitmap_font);
+
+                    let character_style = builder.clone().build();
+                    let tabular_chars = String::from($tabular_chars);
+                    let tabular_style = builder.tabular_chars(&tabular_chars).build();
+                    assert_eq!(tabular_style.tabular_width, width);
+                    for text in $texts {
+                        let metrics = tabular_style.measure_string(text, position, Baseline::Alphabetic);
+                        let expected = position.x as f32 + text.chars().count() as f32 * width;
+                        assert_eq!(metrics.next_position.x, expected as i32, "\n text: {text:?}");
+                    }
+
+                    for (c, advance_width) in advance_widths {
+                        let slice = c.encode_utf8(&mut [0; 4]).to_owned();
+                        let [glyph_points, tabular_glyph_points] =
+                            [&character_style, &tabular_style].map(|character_style| {
+                                let mut display = MockDisplay::new();
+                                let text = Text::with_baseline(&slice, position, character_style.clone(), Baseline::Alphabetic);
+                                text.draw(&mut display).unwrap();
+
+                                let points = display.affected_area().points();
+                                let points = points.filter(|point| display.get_pixel(*point) != Some(Rgb888::BLACK));
+
+                                points.collect::<Vec<_>>()
+                            });
+
+                        let offset = Point::new(((width - advance_width) / 2.0) as i32, 0);
+                        let glyph_points: Vec<_> = glyph_points.into_iter().map(|point| point + offset).collect();
+                        assert_eq!(tabular_glyph_points, glyph_points, "\n slice: {slice:?}");
+                    }
+                }
+            )*
+        }
+    }
+
+    test_draw_text_tabular! {
+        draw_text_tabular_1_400_16_false_1_4_clock,
+            mplus!(font = 1, size = 16, sources = [kern('0'..='9', [":"])]),
+            "0123456789:",
+            ["12:34", "00:00", "11:11"],
+
+        draw_text_tabular_2_700_20_true_1_8_decimal,
+            mplus!(font = 2, weight = 700, size = 20, hint = true, bit_depth = 8, sources = [kern('0'..='9', ["-", "."])]),
+            "0123456789-.",
+            ["-1.5", "10.0", "-.11"],
+    }
+
+    macro_rules! test_draw_text_with_spacing {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $letter_spacing:expr, $word_spacing:expr,
+                $line_height:expr, $text:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let text = $text;
+                    let position = Point::new(4, 32);
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::WHITE)
+                        .background_color(Rgb888::BLUE)
+                        .font(&bitmap_font)
+                        .letter_spacing($letter_spacing)
+                        .word_spacing($word_spacing)
+                        .line_height($line_height)
+                        .build();
+
+                    let mut x = position.x as f32;
+                    for (index, c) in text.char_indices() {
+                        let entry = bitmap_font.get(&text[index..]);
+                        let next = &text[index + c.len_utf8()..];
+                        let next = if next.is_empty() { "" } else { bitmap_font.get(next).key };
+                        x += entry.advance_width_to(next) + $letter_spacing;
+                        if c == ' ' {
+                            x += $word_spacing;
+                        }
+                    }
+
+                    let metrics = character_style.measure_string(text, position, Baseline::Alphabetic);
+                    assert_eq!(metrics.next_position.x, x as i32);
+                    assert_eq!(metrics.bounding_box.size.height, $line_height);
+                    assert_eq!(character_style.line_height(), $line_height);
+
+                    let mut display = MockDisplay::new();
+                    let next_position = character_style
+                        .draw_string(text, position, Baseline::Alphabetic, &mut display)
+                        .unwrap();
+
+                    assert_eq!(next_position, metrics.next_position);
+                    assert_eq!(display.affected_area().top_left.y, metrics.bounding_box.top_left.y);
+                    assert_eq!(display.affected_area().size.height, $line_height);
+
+                    let mut display = MockDisplay::new();
+                    let next_position = character_style
+                        .draw_whitespace(10, position, Baseline::Alphabetic, &mut display)
+                        .unwrap();
+
+                    let line_piece = Rectangle::new(metrics.bounding_box.top_left, Size::new(10, $line_height));
+                    assert_eq!(next_position, position + Point::new(10, 0));
+                    assert_eq!(display.affected_area(), line_piece);
+                }
+            )*
+        }
+    }
+
+    test_draw_text_with_spacing! {
+        draw_text_with_spacing_1_400_12_false_1_4_letters,
+            mplus!(font = 1, size = 12, sources = ["TRACK"]),
+            1.5, 0.0, 20, "TRACK",
+
+        draw_text_with_spacing_2_400_10_false_4_4_words,
+            mplus!(font = 2, size = 10, positions = 4, sources = [kern('A'..='Z', [" "])]),
+            0.25, 3.5, 16, "AV A VA",
+
+        draw_text_with_spacing_code_500_14_true_1_2_negative,
+            mplus!(font = code(100), weight = 500, size = 14, hint = true, bit_depth = 2, sources = ["a b c"]),
+            -0.5, -1.0, 30, "a b c",
+    }
+
+    macro_rules! test_draw_text_scaled {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $scale:expr, $text:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let position = Point::new(2, 3);
+                    let builder = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::WHITE)
+                        .background_color(Rgb888::BLUE)
+                        .font(&bitmap_font)
+                        .shadow(Rgb888::RED, 1, 1)
+                        .underline();
+
+                    let character_style = builder.clone().build();
+                    let scaled_style = builder.scale($scale).build();
+                    let metrics = character_style.measure_string($text, position, Baseline::Top);
+                    let scaled_metrics = scaled_style.measure_string($text, position, Baseline::Top);
+                    let mut bounding_box = metrics.bounding_box;
+                    bounding_box.top_left = position + (bounding_box.top_left - position) * $scale;
+                    bounding_box.size *= $scale;
+                    let next_position = position + (metrics.next_position - position) * $scale;
+                    assert_eq!(scaled_metrics.bounding_box, bounding_box);
+                    assert_eq!(scaled_metrics.next_position, next_position);
+                    assert_eq!(scaled_style.line_height(), character_style.line_height() * $scale);
+
+                    let mut display = MockDisplay::new();
+                    let mut scaled_display = MockDisplay::new();
+                    display.set_allow_overdraw(true);
+                    scaled_display.set_allow_overdraw(true);
+                    let text = Text::with_baseline($text, position, character_style, Baseline::Top);
+                    let scaled_text = Text::with_baseline($text, position, scaled_style, Baseline::Top);
+                    assert_eq!(text.draw(&mut display).unwrap(), metrics.next_position);
+                    assert_eq!(scaled_text.draw(&mut scaled_display).unwrap(), next_position);
+
+                    let area = display.affected_area();
+                    let scaled_area = scaled_display.affected_area();
+                    assert_eq!(scaled_area.top_left, position + (area.top_left - position) * $scale);
+                    assert_eq!(scaled_area.size, area.size * $scale);
+                    for point in scaled_area.points() {
+                        let x = (point.x - position.x).div_euclid($scale) + position.x;
+                        let y = (point.y - position.y).div_euclid($scale) + position.y;
+                        let color = display.get_pixel(Point::new(x, y));
+                        assert_eq!(scaled_display.get_pixel(point), color, "\n point: {point:?}");
+                    }
+                }
+            )*
+        }
+    }
+
+    test_draw_text_scaled! {
+        draw_text_scaled_1_400_8_false_1_4_by_2,
+            mplus!(font = 1, size = 8, sources = ["Scale"]),
+            2, "Scale",
+
+        draw_text_scaled_2_700_6_true_2_8_by_4,
+            mplus!(font = 2, weight = 700, size = 6, hint = true, positions = 2, bit_depth = 8, sources = ["1:2"]),
+            4, "1:2",
+
+        draw_text_scaled_code_400_10_false_1_1_by_3,
+            mplus!(font = code(100), size = 10, bit_depth = 1, sources = ["x2"]),
+            3, "x2",
+    }
+
+    #[test]
+    fn draw_whitespace_scaled() {
+        let bitmap_font = mplus!(font = 1, size = 10, sources = [" "]);
+        let position = Point::new(5, 40);
+        let character_style = BitmapFontStyleBuilder::new()
+            .text_color(Rgb888::WHITE)
+            .background_color(Rgb888::BLUE)
+            .font(&bitmap_font)
+            .scale(3)
+            .build();
+
+        let mut display = MockDisplay::new();
+        let next_position = character_style
+            .draw_whitespace(7, position, Baseline::Alphabetic, &mut display)
+            .unwrap();
+
+        let metrics = character_style.measure_string("", position, Baseline::Alphabetic);
+        let height = metrics.bounding_box.size.height;
+        let line_piece = Rectangle::new(metrics.bounding_box.top_left, Size::new(7, height));
+        assert_eq!(next_position, Point::new(12, 40));
+        assert_eq!(character_style.line_height(), height);
+        assert_eq!(height % 3, 0);
+        assert_eq!(display.affected_area(), line_piece);
+    }
+
+    macro_rules! test_caret_positions {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $text:expr, $expected_indices:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let position = Point::new(3, 30);
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::WHITE)
+                        .background_color(Rgb888::BLACK)
+                        .font(&bitmap_font)
+                        .build();
+
+                    let carets: Vec<_> = character_style.caret_positions($text, position).collect();
+                    let indices: Vec<_> = carets.iter().map(|(index, _)| *index).collect();
+                    assert_eq!(indices, $expected_indices);
+                    assert_eq!(carets.first(), Some(&(0, position)));
+
+                    let metrics = character_style.measure_string($text, position, Baseline::Alphabetic);
+                    assert_eq!(carets.last(), Some(&($text.len(), metrics.next_position)));
+
+                    for pair in carets.windows(2) {
+                        let [(index, caret), (next_index, next_caret)] = pair else {
+                            unreachable!();
+                        };
+
+                        assert!(caret.x < next_caret.x, "\n carets: {pair:?}");
+                        assert_eq!(character_style.hit_test($text, position, *caret), *index);
+                        assert_eq!(character_style.hit_test($text, position, *next_caret), *next_index);
+
+                        let middle = (caret.x + next_caret.x) / 2;
+                        let left = Point::new(middle - 1, position.y + 5);
+                        let right = Point::new(middle + 2, position.y - 5);
+                        assert_eq!(character_style.hit_test($text, position, left), *index);
+                        assert_eq!(character_style.hit_test($text, position, right), *next_index);
+                    }
+
+                    let mut display = MockDisplay::new();
+                    let text = Text::with_baseline($text, position, character_style.clone(), Baseline::Alphabetic);
+                    text.draw(&mut display).unwrap();
+
+                    let bounds = character_style.measure_bounds($text, position, Baseline::Alphabetic);
+                    let advance_width = metrics.next_position.x - position.x;
+                    assert_eq!(bounds.advance_bounds.top_left, metrics.bounding_box.top_left);
+                    assert_eq!(bounds.advance_bounds.size.width, advance_width as u32);
+                    assert_eq!(bounds.advance_bounds.size.height, metrics.bounding_box.size.height);
+
+                    let points = display.affected_area().points();
+                    let points = points.filter(|point| display.get_pixel(*point) != Some(Rgb888::BLACK));
+                    for point in points {
+                        assert!(bounds.ink_bounds.contains(point), "\n point: {point:?}");
+                    }
+                }
+            )*
+        }
+    }
+
+    test_caret_positions! {
+        caret_positions_1_400_12_false_1_4_ligature,
+            mplus!(font = 1, size = 12, sources = [kern('a'..='z', ["ffi"])]),
+            "office",
+            [0, 1, 4, 5, 6],
+
+        caret_positions_2_500_14_true_4_4_kerning,
+            mplus!(font = 2, weight = 500, size = 14, hint = true, positions = 4, sources = [kern('A'..='Z', ["."])]),
+            "AVA.",
+            [0, 1, 2, 3, 4],
+
+        caret_positions_code_400_10_false_1_2_kana,
+            mplus!(font = code(100), size = 10, bit_depth = 2, sources = ["かな1"]),
+            "かな1",
+            [0, 3, 6, 7],
+
+        caret_positions_1_400_10_false_1_4_empty,
+            mplus!(font = 1, size = 10, sources = ["a"]),
+            "",
+            [0],
+    }
+
+    #[test]
+    fn measure_bounds_rotated_and_scaled() {
+        let bitmap_font = mplus!(font = 1, size = 10, sources = ["Tj"]);
+        let position = Point::new(40, 10);
+        let builder = BitmapFontStyleBuilder::new()
+            .text_color(Rgb888::WHITE)
+            .background_color(Rgb888::BLACK)
+            .font(&bitmap_font);
+
+        let character_style = builder.clone().build();
+        let rotated_style = builder.rotation(Rotation::Deg90).scale(2).build();
+        let bounds = character_style.measure_bounds("Tj", position, Baseline::Top);
+        let rotated_bounds = rotated_style.measure_bounds("Tj", position, Baseline::Top);
+        assert_eq!(
+            rotated_bounds.advance_bounds.size.height,
+            bounds.advance_bounds.size.width * 2
+        );
+        assert_eq!(
+            rotated_bounds.ink_bounds.size.width,
+            bounds.ink_bounds.size.height * 2
+        );
+
+        let carets: Vec<_> = rotated_style.caret_positions("Tj", position).collect();
+        let [(0, first), (1, _), (2, last)] = carets[..] else {
+            panic!("\n carets: {carets:?}");
+        };
+
+        let metrics = rotated_style.measure_string("Tj", position, Baseline::Top);
+        assert_eq!(first, position);
+        assert_eq!(last, metrics.next_position);
+        assert_eq!(
+            rotated_style.hit_test("Tj", position, last + Point::new(7, 1)),
+            2
+        );
+    }
+
+    macro_rules! test_draw_paragraph {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $width:expr, $justification:expr, $text:expr,
+                $expected_lines:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let position = Point::new(2, 1);
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::WHITE)
+                        .background_color(Rgb888::BLUE)
+                        .font(&bitmap_font)
+                        .build();
+
+                    let lines: Vec<_> = character_style.lines($text, $width).collect();
+                    let texts: Vec<_> = lines.iter().map(|line| line.text).collect();
+                    assert_eq!(texts, $expected_lines);
+                    for line in &lines {
+                        assert!(line.width <= $width, "\n line: {line:?}");
+                    }
+
+                    let line_height = character_style.line_height();
+                    let height = line_height * lines.len() as u32;
+                    let metrics = character_style.measure_paragraph($text, position, $width);
+                    assert_eq!(metrics.bounding_box, Rectangle::new(position, Size::new($width, height)));
+                    assert_eq!(metrics.next_position, position + Point::new(0, height as i32));
+
+                    let mut display = MockDisplay::new();
+                    display.set_allow_overdraw(true);
+                    let next_position = character_style
+                        .draw_paragraph($text, position, $width, $justification, &mut display)
+                        .unwrap();
+
+                    assert_eq!(next_position, metrics.next_position);
+                    for (index, line) in lines.iter().enumerate() {
+                        let top = position.y + (line_height * index as u32) as i32;
+                        let line_strip = Rectangle::new(Point::new(0, top), Size::new(64, line_height));
+                        let points = line_strip.points().filter(|point| display.get_pixel(*point).is_some());
+                        let (left, right) = points.fold((i32::MAX, i32::MIN), |(left, right), point| {
+                            (left.min(point.x), right.max(point.x + 1))
+                        });
+
+                        let extra_width = ($width - line.width) as i32;
+                        let (expected_left, expected_right) = match $justification {
+                            Justification::Full if !line.ends_paragraph => (0, $width as i32),
+                            Justification::Left | Justification::Full => (0, line.width as i32),
+                            Justification::Center => (extra_width / 2, extra_width / 2 + line.width as i32),
+                            Justification::Right => (extra_width, $width as i32),
+                        };
+                        assert_eq!(left, position.x + expected_left, "\n line: {line:?}");
+                        assert!((right - position.x - expected_right).abs() <= 1, "\n line: {line:?}, right: {right}");
+                    }
+                }
+            )*
+        }
+    }
+
+    test_draw_paragraph! {
+        draw_paragraph_1_400_10_false_1_4_left,
+            mplus!(font = 1, size = 10, sources = ["日本語の文章です。"]),
+            45, Justification::Left,
+            "日本語の文章です。",
+            ["日本語の", "文章で", "す。"],
+
+        draw_paragraph_1_400_10_false_1_4_full,
+            mplus!(font = 1, size = 10, sources = ["「日本語」の文章です。"]),
+            45, Justification::Full,
+            "「日本語」の文章です。",
+            ["「日本", "語」の文", "章です。"],
+
+        draw_paragraph_2_400_10_false_4_4_center,
+            mplus!(font = 2, size = 10, positions = 4, sources = ["Left, center, right\nand full."]),
+            60, Justification::Center,
+            "Left, center, right\nand full.",
+            ["Left, center,", "right", "and full."],
+
+        draw_paragraph_2_400_10_false_4_4_right,
+            mplus!(font = 2, size = 10, positions = 4, sources = ["Left, center, right\nand full."]),
+            60, Justification::Right,
+            "Left, center, right\nand full.",
+            ["Left, center,", "right", "and full."],
+
+        draw_paragraph_2_400_10_false_4_4_full,
+            mplus!(font = 2, size = 10, positions = 4, sources = ["Left, center, right and full."]),
+            60, Justification::Full,
+            "Left, center, right and full.",
+            ["Left, center,", "right and", "full."],
+    }
+
+    macro_rules! test_draw_truncated {
+        (
+            $(
+                $fn_ident:ident, $bitmap_font:expr, $ellipsis:expr, $max_width:expr, $truncation:expr,
+                $text:expr, $expected_text:expr,
+            )*
+        ) => {
+            $(
+                #[test]
+                fn $fn_ident() {
+                    let bitmap_font = $bitmap_font;
+                    let position = Point::new(2, 16);
+                    let character_style = BitmapFontStyleBuilder::new()
+                        .text_color(Rgb888::WHITE)
+                        .font(&bitmap_font)
+                        .ellipsis($ellipsis)
+                        .build();
+
+                    let mut display = MockDisplay::new();
+                    display.set_allow_overdraw(true);
+                    let next_position = character_style
+                        .draw_truncated($text, position, Baseline::Alphabetic, $max_width, $truncation, &mut display)
+                        .unwrap();
+
+                    let width = next_position.x - position.x;
+                    assert!(width <= $max_width as i32, "\n width: {width}");
+
+                    let mut expected_display = MockDisplay::new();
+                    expected_display.set_allow_overdraw(true);
+                    let expected_next_position = Text::with_baseline($expected_text, position, character_style, Baseline::Alphabetic)
+                        .draw(&mut expected_display)
+                        .unwrap();
+
+                    assert_eq!(next_position, expected_next_position);
+                    display.assert_eq(&expected_display);
+                }
+            )*
+        }
+    }
+
+    test_draw_truncated! {
+        draw_truncated_1_400_10_false_1_4_fits,
+            mplus!(font = 1, size = 10, sources = ["日本語の文章です。", "…"]),
+            "…", 60, Truncation::End,
+            "日本語の文章", "日本語の文章",
+
+        draw_truncated_1_400_10_false_1_4_end,
+            mplus!(font = 1, size = 10, sources = ["日本語の文章です。", "…"]),
+            "…", 40, Truncation::End,
+            "日本語の文章です。", "日本語…",
+
+        draw_truncated_1_400_10_false_1_4_start,
+            mplus!(font = 1, size = 10, sources = ["日本語の文章です。", "…"]),
+            "…", 40, Truncation::Start,
+            "日本語の文章です。", "…です。",
+
+        draw_truncated_1_400_10_false_1_4_middle,
+            mplus!(font = 1, size = 10, sources = ["日本語の文章です。", "…"]),
+            "…", 50, Truncation::Middle,
+            "日本語の文章です。", "日本…す。",
+
+        draw_truncated_1_400_10_false_1_4_narrow,
+            mplus!(font = 1, size = 10, sources = ["日本語の文章です。", "…"]),
+            "…", 10, Truncation::Middle,
+            "日本語の文章です。", "…",
+
+    }
+
+    #[test]
+    fn draw_truncated_without_ellipsis_glyph() {
+        let bitmap_font = mplus!(font = 2, size = 10, sources = ["Hello, world!", "..."]);
+        let position = Point::new(2, 16);
+        let max_width = 40;
+        let character_style = BitmapFontStyle::new(&bitmap_font, Rgb888::WHITE);
+        let expected_style = BitmapFontStyleBuilder::new()
+            .text_color(Rgb888::WHITE)
+            .font(&bitmap_font)
+            .ellipsis("...")
+            .build();
+        assert_eq!(character_style.ellipsis, "…");
+        assert!(matches!(bitmap_font.get("…").glyph(0), Some((0, _, _))));
+
+        for truncation in [Truncation::End, Truncation::Start, Truncation::Middle] {
+            let text = "Hello, world!";
+            let mut display = MockDisplay::new();
+            display.set_allow_overdraw(true);
+            let next_position = character_style
+                .draw_truncated(
+                    text,
+                    position,
+                    Baseline::Alphabetic,
+                    max_width,
+                    truncation,
+                    &mut display,
+                )
+                .unwrap();
+
+            let mut expected_display = MockDisplay::new();
+            expected_display.set_allow_overdraw(true);
+            let expected_next_position = expected_style
+                .draw_truncated(
+                    text,
+                    position,
+                    Baseline::Alphabetic,
+                    max_width,
+                    truncation,
+                    &mut expected_display,
+                )
+                .unwrap();
+
+            assert_eq!(
+                next_position, expected_next_position,
+                "\n truncation: {truncation:?}"
+            );
+            display.assert_eq(&expected_display);
+        }
+    }
+
+    #[test]
+    fn draw_truncated_proportional() {
+        let bitmap_font = mplus!(font = 2, size = 10, sources = ["Hello, world!", "..."]);
+        let position = Point::new(2, 16);
+        let max_width = 40;
+        let character_style = BitmapFontStyleBuilder::new()
+            .text_color(Rgb888::WHITE)
+            .font(&bitmap_font)
+            .ellipsis("...")
+            .build();
+
+        for (truncation, expected_text) in [
+            (Truncation::End, "Hello,..."),
+            (Truncation::Start, "...world!"),
+            (Truncation::Middle, "He...rld!"),
+        ] {
+            let mut display = MockDisplay::new();
+            display.set_allow_overdraw(true);
+            let text = "Hello, world!";
+            let next_position = character_style
+                .draw_truncated(
+                    text,
+                    position,
+                    Baseline::Alphabetic,
+                    max_width,
+                    truncation,
+                    &mut display,
+                )
+                .unwrap();
+
+            let metrics =
+                character_style.measure_string(expected_text, position, Baseline::Alphabetic);
+            let offset = next_position - metrics.next_position;
+            assert!(
+                offset.x.abs() <= 1 && offset.y == 0,
+                "\n truncation: {truncation:?}"
+            );
+            assert_eq!(display.affected_area().top_left.x, position.x);
+            assert!(display.affected_area().size.width <= max_width);
+        }
+    }
+
+    #[test]
+    fn draw_text_normalized() {
+        let bitmap_font = mplus!(font = 2, size = 10, sources = ["Café"]);
+        let character_style = BitmapFontStyle::new(&bitmap_font, Rgb888::WHITE);
+        let position = Point::new(2, 16);
+
+        let mut display = MockDisplay::new();
+        let next_position = Text::new("Cafe\u{301}", position, character_style.clone())
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected_display = MockDisplay::new();
+        let expected_next_position = Text::new("Café", position, character_style)
+            .draw(&mut expected_display)
+            .unwrap();
+
+        assert_eq!(next_position, expected_next_position);
+        display.assert_eq(&expected_display);
+    }
 }